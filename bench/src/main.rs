@@ -0,0 +1,296 @@
+//! Plays seeded games with a bot and reports how it fares on each board.
+//!
+//! Every game is reproducible from its seed, so the report on stdout only
+//! changes when the generator or a bot does. Run times go to stderr.
+
+use std::process;
+use std::time::Instant;
+
+use klaptik::Point;
+use minesweeper_core::board::Board;
+use minesweeper_core::game::*;
+use minesweeper_core::player::*;
+use minesweeper_core::settings::Difficulty;
+use minesweeper_core::solver::Solver;
+use minesweeper_core::tiles::TileSize;
+
+const USAGE: &str = "\
+Usage: minesweeper-bench [--bot solver|random] [--games N] [--seed N]
+                         [--difficulty easy|normal|hard] [--size WxH --mines N]
+
+Plays N games per board with seeds starting at --seed, on every difficulty unless
+one is picked or a custom board is given.";
+
+#[derive(Copy, Clone)]
+enum BotKind {
+    Solver,
+    Random,
+}
+
+struct Options {
+    bot: BotKind,
+    games: u32,
+    seed: u32,
+    boards: Vec<GameConfig>,
+}
+
+impl Options {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut bot = BotKind::Solver;
+        let mut games = 1000;
+        let mut seed = 1;
+        let mut difficulty = None;
+        let mut size = None;
+        let mut bombs = None;
+
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or(format!("missing value for {}", arg));
+            match arg.as_str() {
+                "--bot" => {
+                    bot = match value()?.as_str() {
+                        "solver" => BotKind::Solver,
+                        "random" => BotKind::Random,
+                        other => return Err(format!("unknown bot {}", other)),
+                    }
+                }
+                "--games" => games = parse_number(&value()?)?,
+                "--seed" => seed = parse_number(&value()?)?,
+                "--difficulty" => {
+                    difficulty = Some(match value()?.as_str() {
+                        "easy" => Difficulty::Easy,
+                        "normal" => Difficulty::Normal,
+                        "hard" => Difficulty::Hard,
+                        other => return Err(format!("unknown difficulty {}", other)),
+                    })
+                }
+                "--size" => {
+                    let value = value()?;
+                    let (w, h) = value
+                        .split_once('x')
+                        .ok_or(format!("size must look like 16x6, got {}", value))?;
+                    size = Some((parse_number(w)? as usize, parse_number(h)? as usize));
+                }
+                "--mines" => bombs = Some(parse_number(&value()?)? as usize),
+                "-h" | "--help" => return Err(String::new()),
+                _ => return Err(format!("unknown option {}", arg)),
+            }
+        }
+
+        let boards = match (size, bombs, difficulty) {
+            (Some((width, height)), Some(bombs), None) => {
+                if width == 0
+                    || width > Board::MAX_WIDTH
+                    || height == 0
+                    || height > Board::MAX_HEIGHT
+                {
+                    return Err(format!(
+                        "board size must be within {}x{}",
+                        Board::MAX_WIDTH,
+                        Board::MAX_HEIGHT
+                    ));
+                }
+                if bombs >= width * height {
+                    return Err(format!(
+                        "{} mines do not fit a {}x{} board",
+                        bombs, width, height
+                    ));
+                }
+                vec![GameConfig {
+                    width,
+                    height,
+                    bombs,
+                    tiles: TileSize::Small,
+                }]
+            }
+            (None, None, Some(difficulty)) => vec![difficulty.config()],
+            (None, None, None) => Difficulty::ALL.iter().map(Difficulty::config).collect(),
+            (_, _, Some(_)) => return Err("pick a difficulty or a custom board, not both".into()),
+            _ => return Err("a custom board needs both --size and --mines".into()),
+        };
+        if games == 0 {
+            return Err("play at least one game".into());
+        }
+
+        Ok(Self {
+            bot,
+            games,
+            seed,
+            boards,
+        })
+    }
+}
+
+fn parse_number(value: &str) -> Result<u32, String> {
+    value
+        .parse()
+        .map_err(|_| format!("expected a number, got {}", value))
+}
+
+/// A player that also counts the moves it could not prove safe.
+trait Bot: Player {
+    fn guesses(&self) -> u32;
+}
+
+impl Bot for Solver {
+    fn guesses(&self) -> u32 {
+        Solver::guesses(self)
+    }
+}
+
+/// Opens closed tiles at random, the baseline any real bot has to beat.
+struct Random {
+    state: u32,
+    guesses: u32,
+}
+
+impl Random {
+    fn new(seed: u32) -> Self {
+        Self {
+            state: seed.max(1),
+            guesses: 0,
+        }
+    }
+
+    /// Xorshift, good enough to spread picks over the board.
+    fn next_u32(&mut self) -> u32 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 17;
+        self.state ^= self.state << 5;
+        self.state
+    }
+}
+
+impl Player for Random {
+    fn next_move(&mut self, view: &View) -> Option<Move> {
+        if !matches!(view.status(), GameStatus::Bootstrap | GameStatus::Playing) {
+            return None;
+        }
+        let width = view.width();
+        let closed: Vec<Point> = (0..width * view.height())
+            .map(|idx| Point::new((idx % width) as i32, (idx / width) as i32))
+            .filter(|&pos| view.cell(pos) == Cell::Closed)
+            .collect();
+        if closed.is_empty() {
+            return None;
+        }
+        self.guesses += 1;
+        let pick = self.next_u32() as usize % closed.len();
+        Some(Move::Open(closed[pick]))
+    }
+}
+
+impl Bot for Random {
+    fn guesses(&self) -> u32 {
+        self.guesses
+    }
+}
+
+#[derive(Default)]
+struct Tally {
+    games: u32,
+    won: u32,
+    stalled: u32,
+    guesses: u64,
+    three_bv: u64,
+}
+
+impl Tally {
+    fn average(&self, total: u64) -> f64 {
+        total as f64 / self.games as f64
+    }
+}
+
+/// Plays one game to the end, returns false if the bot stopped moving or ran
+/// out of moves first.
+fn play(game: &mut Minesweeper, bot: &mut impl Bot) -> bool {
+    let mut moves_left = 4 * game.board().tiles().len();
+    while !matches!(game.status(), GameStatus::Win | GameStatus::GameOver) {
+        let next = match bot.next_move(&View::new(game)) {
+            Some(next) if moves_left > 0 => next,
+            _ => return false,
+        };
+        moves_left -= 1;
+        for button in next.buttons(game.board().cursor()) {
+            game.button_click(button);
+        }
+    }
+    true
+}
+
+fn bench<B: Bot>(options: &Options, config: GameConfig, new_bot: impl Fn(u32) -> B) -> Tally {
+    let mut tally = Tally::default();
+    for seed in (0..options.games).map(|game| options.seed.wrapping_add(game)) {
+        let mut game = Minesweeper::new(config);
+        game.seed_random(seed);
+        let mut bot = new_bot(seed);
+
+        tally.games += 1;
+        if !play(&mut game, &mut bot) {
+            tally.stalled += 1;
+        } else if game.status() == GameStatus::Win {
+            tally.won += 1;
+        }
+        tally.guesses += bot.guesses() as u64;
+        tally.three_bv += game.three_bv() as u64;
+    }
+    tally
+}
+
+fn main() {
+    let options = match Options::parse(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(err) if err.is_empty() => {
+            println!("{}", USAGE);
+            return;
+        }
+        Err(err) => {
+            eprintln!("{}\n\n{}", err, USAGE);
+            process::exit(2);
+        }
+    };
+
+    let bot = match options.bot {
+        BotKind::Solver => "solver",
+        BotKind::Random => "random",
+    };
+    println!(
+        "bot {}, seeds {}..={}",
+        bot,
+        options.seed,
+        options.seed.wrapping_add(options.games - 1)
+    );
+    println!();
+    println!(
+        "{:<8} {:>5} {:>6} {:>6} {:>7} {:>8} {:>8} {:>6}",
+        "board", "mines", "games", "won", "win%", "stalled", "guesses", "3bv"
+    );
+
+    for &config in options.boards.iter() {
+        let started = Instant::now();
+        let tally = match options.bot {
+            BotKind::Solver => bench(&options, config, |_| Solver::new()),
+            BotKind::Random => bench(&options, config, Random::new),
+        };
+        let elapsed = started.elapsed();
+
+        let board = format!("{}x{}", config.width, config.height);
+        println!(
+            "{:<8} {:>5} {:>6} {:>6} {:>6.1}% {:>8} {:>8.2} {:>6.1}",
+            board,
+            config.bombs,
+            tally.games,
+            tally.won,
+            100.0 * tally.won as f64 / tally.games as f64,
+            tally.stalled,
+            tally.average(tally.guesses),
+            tally.average(tally.three_bv),
+        );
+        eprintln!(
+            "{}: {} games in {:.2?}, {:.2?} per game",
+            board,
+            tally.games,
+            elapsed,
+            elapsed / tally.games
+        );
+    }
+}
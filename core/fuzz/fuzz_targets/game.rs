@@ -0,0 +1,73 @@
+//! Plays the bytes as a game: a seed, board size and mine count, then one
+//! input per byte, checking the board after every step.
+
+#![no_main]
+
+use klaptik::Point;
+use libfuzzer_sys::fuzz_target;
+use minesweeper_core::board::*;
+use minesweeper_core::game::*;
+use minesweeper_core::tiles::TileSize;
+
+fuzz_target!(|data: &[u8]| {
+    if data.len() < 8 {
+        return;
+    }
+    let (header, inputs) = data.split_at(8);
+
+    let seed = u32::from_le_bytes([header[0], header[1], header[2], header[3]]);
+    let width = 1 + header[4] as usize % Board::MAX_WIDTH;
+    let height = 1 + header[5] as usize % Board::MAX_HEIGHT;
+    let bombs = u16::from_le_bytes([header[6], header[7]]) as usize % (width * height);
+    let tiles = [TileSize::Large, TileSize::Medium, TileSize::Small][header[4] as usize % 3];
+    let config = GameConfig {
+        width,
+        height,
+        bombs,
+        tiles,
+    };
+
+    let mut game = Minesweeper::new(config);
+    game.seed_random(seed);
+
+    for &input in inputs {
+        let status = game.status();
+        match input % 10 {
+            0 => game.button_click(GameButton::A),
+            1 => game.button_click(GameButton::B),
+            2 => game.button_click(GameButton::Chord),
+            3 => game.button_click(GameButton::DPad(Dir::Up)),
+            4 => game.button_click(GameButton::DPad(Dir::Right)),
+            5 => game.button_click(GameButton::DPad(Dir::Down)),
+            6 => game.button_click(GameButton::DPad(Dir::Left)),
+            7 => game.tick(),
+            8 => game.pause(Pause::Disconnected),
+            _ => game.resume(),
+        }
+
+        let board = game.board();
+        assert!(board.contains(board.cursor()), "cursor left the board");
+        assert!(game.mines_left() <= bombs);
+        if status != GameStatus::Bootstrap {
+            check_layout(board, bombs);
+        }
+    }
+});
+
+fn check_layout(board: &Board, bombs: usize) {
+    let is_bomb = |pos| board.tile_at(pos).content() == TileContent::Bomb;
+    let mut planted = 0;
+    for y in 0..board.height() {
+        for x in 0..board.width() {
+            let pos = Point::new(x as i32, y as i32);
+            match board.tile_at(pos).content() {
+                TileContent::Bomb => planted += 1,
+                TileContent::Hint(hint) => {
+                    let around = board.neighbors(pos).filter(|&pos| is_bomb(pos)).count();
+                    assert_eq!(hint as usize, around, "wrong hint at {:?}", pos);
+                }
+            }
+        }
+    }
+    assert_eq!(planted, bombs);
+}
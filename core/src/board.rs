@@ -0,0 +1,292 @@
+use klaptik::*;
+
+use crate::viewport::*;
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum TileStatus {
+    Closed,
+    Flagged,
+    Opened,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum TileContent {
+    Bomb,
+    Hint(u8),
+}
+
+#[derive(Copy, Clone)]
+pub struct Tile {
+    status: TileStatus,
+    content: TileContent,
+}
+
+impl Default for Tile {
+    fn default() -> Self {
+        Self {
+            status: TileStatus::Closed,
+            content: TileContent::Hint(0),
+        }
+    }
+}
+
+impl Tile {
+    pub fn new(status: TileStatus, content: TileContent) -> Self {
+        Self { status, content }
+    }
+
+    pub fn status(&self) -> TileStatus {
+        self.status
+    }
+
+    pub fn content(&self) -> TileContent {
+        self.content
+    }
+}
+
+impl From<&Tile> for Glyph {
+    fn from(tile: &Tile) -> Self {
+        match tile.status() {
+            TileStatus::Closed => b'-',
+            TileStatus::Flagged => b'.',
+            TileStatus::Opened => match tile.content() {
+                TileContent::Bomb => b'/',
+                TileContent::Hint(0) => b',',
+                TileContent::Hint(hint) => b'0' + hint,
+            },
+        }
+    }
+}
+
+pub struct Board {
+    width: usize,
+    height: usize,
+    cursor: Point,
+    viewport: Viewport,
+    tiles: [Tile; Self::MAX_TILES],
+    revision: u32,
+}
+
+impl Board {
+    pub const MAX_WIDTH: usize = 30;
+    pub const MAX_HEIGHT: usize = 16;
+    pub const MAX_TILES: usize = Self::MAX_HEIGHT * Self::MAX_WIDTH;
+
+    pub fn new(width: usize, height: usize) -> Self {
+        let mut board = Self {
+            width: 0,
+            height: 0,
+            cursor: Point::new(0, 0),
+            viewport: Viewport::new(width, height),
+            tiles: [Tile::default(); Self::MAX_TILES],
+            revision: 0,
+        };
+        board.resize(width, height);
+        board
+    }
+
+    pub fn resize(&mut self, width: usize, height: usize) {
+        assert!(width <= Self::MAX_WIDTH && height <= Self::MAX_HEIGHT);
+        self.width = width;
+        self.height = height;
+        self.reset();
+        self.move_cursor(Point::new(width as i32 / 2, height as i32 / 2));
+    }
+
+    pub fn reset(&mut self) {
+        for tile in self.tiles.iter_mut() {
+            *tile = Tile::default()
+        }
+        self.touch();
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Point) -> bool {
+        pos.x >= 0 && pos.y >= 0 && pos.x < self.width as i32 && pos.y < self.height as i32
+    }
+
+    pub fn neighbors(&self, origin: Point) -> Neighbors {
+        Neighbors {
+            origin,
+            width: self.width as i32,
+            height: self.height as i32,
+            next: 0,
+        }
+    }
+
+    pub fn set_status_at(&mut self, pos: Point, status: TileStatus) {
+        self.tiles[self.point_offset(pos)].status = status;
+        self.touch();
+    }
+
+    pub fn set_content_at(&mut self, pos: Point, content: TileContent) {
+        self.tiles[self.point_offset(pos)].content = content;
+        self.touch();
+    }
+
+    pub fn tiles(&self) -> &[Tile] {
+        &self.tiles[..self.width * self.height]
+    }
+
+    pub fn tile_at(&self, pos: Point) -> Tile {
+        self.tiles[self.point_offset(pos)]
+    }
+
+    pub fn cursor(&self) -> Point {
+        self.cursor
+    }
+
+    pub fn move_cursor(&mut self, target: Point) {
+        self.cursor = target;
+        self.viewport.follow(target, self.width, self.height);
+        self.touch();
+    }
+
+    pub fn viewport(&self) -> &Viewport {
+        &self.viewport
+    }
+
+    /// Sets how many tiles fit on screen and scrolls the cursor back into view.
+    pub fn set_window(&mut self, cols: usize, rows: usize) {
+        self.viewport.set_window(cols, rows);
+        self.viewport.follow(self.cursor, self.width, self.height);
+        self.touch();
+    }
+
+    /// Bechtel's Board Benchmark Value: the fewest clicks that clear the board,
+    /// one per opening of empty tiles plus one per hint not bordering an opening.
+    pub fn three_bv(&self) -> usize {
+        let is_empty = |offset: usize| self.tiles[offset].content == TileContent::Hint(0);
+        // Bit sets instead of arrays keep the scratch small enough for the MCU
+        let mut reached = OffsetSet::default();
+        let mut pending = OffsetSet::default();
+        let mut clicks = 0;
+
+        for start in 0..self.width * self.height {
+            if reached.contains(start) || !is_empty(start) {
+                continue;
+            }
+            clicks += 1;
+            reached.insert(start);
+            pending.insert(start);
+            while let Some(offset) = pending.pop() {
+                for pos in self.neighbors(self.offset_point(offset)) {
+                    let offset = self.point_offset(pos);
+                    if reached.insert(offset) && is_empty(offset) {
+                        pending.insert(offset);
+                    }
+                }
+            }
+        }
+
+        let isolated = self
+            .tiles()
+            .iter()
+            .enumerate()
+            .filter(|&(offset, tile)| {
+                !reached.contains(offset) && tile.content != TileContent::Bomb
+            })
+            .count();
+        clicks + isolated
+    }
+
+    /// Counter bumped on every change, used to skip redundant redraws.
+    pub fn revision(&self) -> u32 {
+        self.revision
+    }
+
+    fn touch(&mut self) {
+        self.revision = self.revision.wrapping_add(1);
+    }
+
+    pub(crate) fn point_offset(&self, point: Point) -> usize {
+        point.x as usize + point.y as usize * self.width
+    }
+
+    pub(crate) fn offset_point(&self, offset: usize) -> Point {
+        Point::new((offset % self.width) as i32, (offset / self.width) as i32)
+    }
+}
+
+pub struct Neighbors {
+    origin: Point,
+    width: i32,
+    height: i32,
+    next: usize,
+}
+
+impl Neighbors {
+    const NEIGHBORHOOD: [(i32, i32); 8] = [
+        (-1, -1),
+        (-1, 0),
+        (-1, 1),
+        (0, 1),
+        (1, 1),
+        (1, 0),
+        (1, -1),
+        (0, -1),
+    ];
+}
+
+impl Iterator for Neighbors {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.next >= Self::NEIGHBORHOOD.len() {
+                return None;
+            }
+
+            let addr = Self::NEIGHBORHOOD[self.next];
+            self.next += 1;
+
+            let x = self.origin.x + addr.0;
+            let y = self.origin.y + addr.1;
+
+            if x >= 0 && y >= 0 && x < self.width && y < self.height {
+                return Some(Point::new(x, y));
+            }
+        }
+    }
+}
+
+/// One bit per tile offset, scratch for walking the board without a
+/// `MAX_TILES` sized array on the stack.
+#[derive(Default)]
+pub(crate) struct OffsetSet {
+    words: [u32; Board::MAX_TILES.div_ceil(32)],
+}
+
+impl OffsetSet {
+    pub fn contains(&self, offset: usize) -> bool {
+        self.words[offset / 32] & (1 << (offset % 32)) != 0
+    }
+
+    /// Adds an offset, returns `false` if it was already there.
+    pub fn insert(&mut self, offset: usize) -> bool {
+        let present = self.contains(offset);
+        self.words[offset / 32] |= 1 << (offset % 32);
+        !present
+    }
+
+    /// Removes and returns the lowest offset.
+    pub fn pop(&mut self) -> Option<usize> {
+        let (index, word) = self
+            .words
+            .iter_mut()
+            .enumerate()
+            .find(|(_, word)| **word != 0)?;
+        let bit = word.trailing_zeros() as usize;
+        *word &= *word - 1;
+        Some(index * 32 + bit)
+    }
+}
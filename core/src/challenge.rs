@@ -0,0 +1,247 @@
+//! Boards that can be replayed elsewhere: a daily board picked by date and
+//! board codes that carry everything needed to deal a board again.
+
+use crate::game::GameConfig;
+use crate::settings::Difficulty;
+
+/// Calendar date within the range the RTC can hold.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Date {
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+impl Default for Date {
+    /// A recent date to start adjusting from when the RTC has not been set.
+    fn default() -> Self {
+        Self {
+            year: 2025,
+            month: 1,
+            day: 1,
+        }
+    }
+}
+
+impl Date {
+    pub const MIN_YEAR: u16 = 2000;
+    pub const MAX_YEAR: u16 = 2099;
+
+    pub fn new(year: u16, month: u8, day: u8) -> Option<Self> {
+        if !(Self::MIN_YEAR..=Self::MAX_YEAR).contains(&year)
+            || !(1..=12).contains(&month)
+            || day == 0
+            || day > days_in_month(year, month)
+        {
+            return None;
+        }
+        Some(Self { year, month, day })
+    }
+
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// Day of the week, 1 for Monday to 7 for Sunday.
+    pub fn weekday(&self) -> u8 {
+        // 2000-01-01 was a Saturday
+        ((self.days() + 5) % 7 + 1) as u8
+    }
+
+    pub fn step_year(&mut self, forward: bool) {
+        self.year = match forward {
+            true if self.year < Self::MAX_YEAR => self.year + 1,
+            false if self.year > Self::MIN_YEAR => self.year - 1,
+            _ => self.year,
+        };
+        self.day = self.day.min(days_in_month(self.year, self.month));
+    }
+
+    pub fn step_month(&mut self, forward: bool) {
+        self.month = wrap(self.month, 12, forward);
+        self.day = self.day.min(days_in_month(self.year, self.month));
+    }
+
+    pub fn step_day(&mut self, forward: bool) {
+        self.day = wrap(self.day, days_in_month(self.year, self.month), forward);
+    }
+
+    /// Seed of the daily board, the same on every device for the same date.
+    pub fn seed(&self) -> u32 {
+        // Murmur3 finalizer spreads neighbouring days over the whole range
+        let mut hash = self.days() ^ 0x4d53_5744;
+        hash ^= hash >> 16;
+        hash = hash.wrapping_mul(0x85eb_ca6b);
+        hash ^= hash >> 13;
+        hash = hash.wrapping_mul(0xc2b2_ae35);
+        hash ^= hash >> 16;
+        hash
+    }
+
+    /// Date as `YYYY-MM-DD`.
+    pub fn format(&self) -> [u8; 10] {
+        let digit = |value: u16| b'0' + (value % 10) as u8;
+        let (year, month, day) = (self.year, self.month as u16, self.day as u16);
+        [
+            digit(year / 1000),
+            digit(year / 100),
+            digit(year / 10),
+            digit(year),
+            b'-',
+            digit(month / 10),
+            digit(month),
+            b'-',
+            digit(day / 10),
+            digit(day),
+        ]
+    }
+
+    /// Days since 2000-01-01.
+    fn days(&self) -> u32 {
+        let years = (Self::MIN_YEAR..self.year)
+            .map(|year| if is_leap(year) { 366 } else { 365 })
+            .sum::<u32>();
+        let months = (1..self.month)
+            .map(|month| days_in_month(self.year, month) as u32)
+            .sum::<u32>();
+        years + months + self.day as u32 - 1
+    }
+}
+
+fn is_leap(year: u16) -> bool {
+    year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400))
+}
+
+fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if is_leap(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Steps through `1..=max`, wrapping around at both ends.
+fn wrap(value: u8, max: u8, forward: bool) -> u8 {
+    match forward {
+        true if value >= max => 1,
+        true => value + 1,
+        false if value <= 1 => max,
+        false => value - 1,
+    }
+}
+
+/// Everything needed to deal a board again: difficulty, board size and seed,
+/// written as ten characters with a checksum, like `7T4KZ-0QM3A`.
+///
+/// The size is implied by the difficulty and only checked when parsing, so a
+/// code from firmware with different presets is rejected rather than dealing
+/// another board.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct BoardCode {
+    difficulty: Difficulty,
+    seed: u32,
+}
+
+impl BoardCode {
+    /// Symbols in a code, shown in two groups of five.
+    pub const SYMBOLS: usize = 10;
+    /// Crockford's base32: no I, L, O or U to mix up with digits.
+    pub const ALPHABET: &'static [u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+    pub fn new(difficulty: Difficulty, seed: u32) -> Self {
+        // Same range as the game generator, so a code names exactly one board
+        let seed = (seed % 0x7fff_ffff).max(1);
+        Self { difficulty, seed }
+    }
+
+    pub fn difficulty(&self) -> Difficulty {
+        self.difficulty
+    }
+
+    pub fn seed(&self) -> u32 {
+        self.seed
+    }
+
+    pub fn config(&self) -> GameConfig {
+        self.difficulty.config()
+    }
+
+    /// Symbol indices into `ALPHABET`.
+    pub fn symbols(&self) -> [u8; Self::SYMBOLS] {
+        let config = self.config();
+        let payload = self.seed as u64
+            | ((config.height - 1) as u64) << 31
+            | ((config.width - 1) as u64) << 35
+            | (self.difficulty as u64) << 40;
+        let packed = payload << 8 | checksum(payload) as u64;
+        core::array::from_fn(|idx| (packed >> (5 * (Self::SYMBOLS - 1 - idx)) & 0x1f) as u8)
+    }
+
+    pub fn from_symbols(symbols: &[u8; Self::SYMBOLS]) -> Option<Self> {
+        let packed = symbols.iter().try_fold(0u64, |acc, &symbol| {
+            (symbol < 32).then_some(acc << 5 | symbol as u64)
+        })?;
+        let payload = packed >> 8;
+        if checksum(payload) != packed as u8 {
+            return None;
+        }
+
+        let difficulty = *Difficulty::ALL.get((payload >> 40) as usize)?;
+        let config = difficulty.config();
+        let width = (payload >> 35 & 0x1f) as usize + 1;
+        let height = (payload >> 31 & 0xf) as usize + 1;
+        let seed = (payload & 0x7fff_ffff) as u32;
+        if (width, height) != (config.width, config.height) || seed == 0 || seed == 0x7fff_ffff {
+            return None;
+        }
+        Some(Self { difficulty, seed })
+    }
+
+    /// Code as `XXXXX-XXXXX`.
+    pub fn format(&self) -> [u8; Self::SYMBOLS + 1] {
+        let symbols = self.symbols();
+        let mut text = [b'-'; Self::SYMBOLS + 1];
+        for (idx, &symbol) in symbols.iter().enumerate() {
+            text[idx + idx / 5] = Self::ALPHABET[symbol as usize];
+        }
+        text
+    }
+
+    /// Reads a typed code, ignoring case, dashes and spaces.
+    pub fn parse(text: &str) -> Option<Self> {
+        let mut symbols = [0; Self::SYMBOLS];
+        let mut len = 0;
+        for byte in text.bytes().filter(|byte| !matches!(byte, b'-' | b' ')) {
+            let byte = match byte.to_ascii_uppercase() {
+                b'I' | b'L' => b'1',
+                b'O' => b'0',
+                byte => byte,
+            };
+            let symbol = Self::ALPHABET.iter().position(|&glyph| glyph == byte)?;
+            *symbols.get_mut(len)? = symbol as u8;
+            len += 1;
+        }
+        if len != Self::SYMBOLS {
+            return None;
+        }
+        Self::from_symbols(&symbols)
+    }
+}
+
+/// CRC-8 over the 42 payload bits, it catches any burst of up to 8 flipped
+/// bits and so every single mistyped symbol.
+fn checksum(payload: u64) -> u8 {
+    (0..42).rev().fold(0, |crc: u8, bit| {
+        let feedback = (crc >> 7 ^ (payload >> bit) as u8) & 1;
+        (crc << 1) ^ (feedback * 0x07)
+    })
+}
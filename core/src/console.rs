@@ -0,0 +1,100 @@
+//! Development console: cheats and inspection commands typed over a debug link.
+//!
+//! ```text
+//! board               print the board as the player sees it
+//! reveal              open every tile
+//! mine <x> <y>        add a mine to the game in progress
+//! seed <n>            seed the mine generator for the next game
+//! cursor <x> <y>      jump the cursor
+//! win | lose          end the game in progress
+//! ```
+
+use core::fmt::{self, Write};
+
+use klaptik::Point;
+
+use crate::game::*;
+use crate::line::*;
+use crate::remote::write_board;
+
+pub enum ConsoleCommand {
+    Board,
+    Reveal,
+    Mine(Point),
+    Seed(u32),
+    Cursor(Point),
+    Win,
+    Lose,
+}
+
+impl ConsoleCommand {
+    pub fn parse(line: &[u8]) -> Option<Self> {
+        let mut words = words(line);
+        let name = words.next()?;
+        let mut args = words.map(number);
+        let mut point = || match (args.next(), args.next()) {
+            (Some(Some(x)), Some(Some(y))) => Some(Point::new(x as i32, y as i32)),
+            _ => None,
+        };
+        let command = match name {
+            b"board" => ConsoleCommand::Board,
+            b"reveal" => ConsoleCommand::Reveal,
+            b"mine" => ConsoleCommand::Mine(point()?),
+            b"cursor" => ConsoleCommand::Cursor(point()?),
+            b"seed" => ConsoleCommand::Seed(args.next()??),
+            b"win" => ConsoleCommand::Win,
+            b"lose" => ConsoleCommand::Lose,
+            _ => return None,
+        };
+        match args.next() {
+            Some(_) => None,
+            None => Some(command),
+        }
+    }
+}
+
+/// Collects console input and runs complete commands on the game.
+#[derive(Default)]
+pub struct Console {
+    line: LineBuffer<32>,
+}
+
+impl Console {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn feed(&mut self, byte: u8, game: &mut Minesweeper, out: &mut impl Write) -> fmt::Result {
+        match self.line.push(byte) {
+            Some(Ok(line)) => match ConsoleCommand::parse(line) {
+                Some(command) => execute(command, game, out),
+                None => writeln!(out, "unknown command: {}", line.escape_ascii()),
+            },
+            Some(Err(LineTooLong)) => writeln!(out, "line too long"),
+            None => Ok(()),
+        }
+    }
+}
+
+pub fn execute(
+    command: ConsoleCommand,
+    game: &mut Minesweeper,
+    out: &mut impl Write,
+) -> fmt::Result {
+    let done = match command {
+        ConsoleCommand::Board => return write_board(game.board(), out),
+        ConsoleCommand::Reveal => {
+            game.reveal_all();
+            true
+        }
+        ConsoleCommand::Mine(pos) => game.place_mine(pos),
+        ConsoleCommand::Seed(seed) => {
+            game.seed_random(seed);
+            true
+        }
+        ConsoleCommand::Cursor(pos) => game.jump_cursor(pos),
+        ConsoleCommand::Win => game.force_status(GameStatus::Win),
+        ConsoleCommand::Lose => game.force_status(GameStatus::GameOver),
+    };
+    writeln!(out, "{}", if done { "done" } else { "not possible now" })
+}
@@ -0,0 +1,96 @@
+use embedded_hal::blocking::i2c::{Read, Write};
+
+use crate::extension::*;
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Link {
+    Probing,
+    // Waits a tick for the id to settle, `probing` until first connected
+    Identifying { probing: bool },
+    Connected,
+    Disconnected { retry_in: u8 },
+}
+
+pub enum ControllerEvent {
+    Idle,
+    Connected,
+    Disconnected,
+    Input(Reading),
+}
+
+pub struct Controller<I2C> {
+    extension: Extension<I2C>,
+    link: Link,
+}
+
+impl<I2C, E> Controller<I2C>
+where
+    I2C: Read<Error = E> + Write<Error = E>,
+{
+    pub const RETRY_TICKS: u8 = 10;
+
+    pub fn new(bus: I2C) -> Self {
+        Self {
+            extension: Extension::new(bus),
+            link: Link::Probing,
+        }
+    }
+
+    pub fn poll(&mut self) -> ControllerEvent {
+        match self.link {
+            Link::Connected => match self.extension.read() {
+                Ok(reading) => ControllerEvent::Input(reading),
+                Err(_err) => {
+                    log_warn!("controller lost: {}", _err);
+                    self.disconnect()
+                }
+            },
+            Link::Disconnected { retry_in } if retry_in > 0 => {
+                self.link = Link::Disconnected {
+                    retry_in: retry_in - 1,
+                };
+                ControllerEvent::Idle
+            }
+            Link::Disconnected { .. } => self.start(false),
+            Link::Probing => self.start(true),
+            Link::Identifying { probing } => match self.extension.identify() {
+                Ok(kind) => self.connect(kind),
+                Err(_err) => self.fail(probing, _err),
+            },
+        }
+    }
+
+    fn start(&mut self, probing: bool) -> ControllerEvent {
+        match self.extension.init() {
+            Ok(()) => {
+                self.link = Link::Identifying { probing };
+                ControllerEvent::Idle
+            }
+            Err(_err) => self.fail(probing, _err),
+        }
+    }
+
+    fn fail(&mut self, probing: bool, _err: ExtensionError<E>) -> ControllerEvent {
+        if probing {
+            log_warn!("no controller found: {}", _err);
+            return self.disconnect();
+        }
+        self.link = Link::Disconnected {
+            retry_in: Self::RETRY_TICKS,
+        };
+        ControllerEvent::Idle
+    }
+
+    fn connect(&mut self, _kind: ExtensionKind) -> ControllerEvent {
+        log_info!("controller connected: {}", _kind);
+        self.link = Link::Connected;
+        ControllerEvent::Connected
+    }
+
+    fn disconnect(&mut self) -> ControllerEvent {
+        self.link = Link::Disconnected {
+            retry_in: Self::RETRY_TICKS,
+        };
+        ControllerEvent::Disconnected
+    }
+}
@@ -0,0 +1,73 @@
+//! Attract mode: a bot plays on the title screen until someone picks up the controller.
+
+use crate::game::*;
+use crate::player::*;
+
+pub struct Demo<P> {
+    player: P,
+    running: bool,
+    plan: Option<Buttons>,
+    pause: u8,
+}
+
+impl<P: Player> Demo<P> {
+    /// Idle time on the title screen before the demo kicks in.
+    pub const START_AFTER_MS: u32 = 20_000;
+    /// Steps to show a finished game before dealing the next one.
+    const END_PAUSE: u8 = 20;
+
+    pub fn new(player: P) -> Self {
+        Self {
+            player,
+            running: false,
+            plan: None,
+            pause: 0,
+        }
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.running
+    }
+
+    pub fn start(&mut self) {
+        self.running = true;
+        self.plan = None;
+        self.pause = 0;
+    }
+
+    pub fn stop(&mut self) {
+        self.running = false;
+        self.plan = None;
+    }
+
+    /// Presses a single button, so the cursor can be seen walking to each move.
+    pub fn step(&mut self, game: &mut Minesweeper) {
+        if !self.running {
+            return;
+        }
+
+        if matches!(game.status(), GameStatus::Win | GameStatus::GameOver) {
+            self.plan = None;
+            if self.pause < Self::END_PAUSE {
+                self.pause += 1;
+            } else {
+                self.pause = 0;
+                // Deals a new board
+                game.button_click(GameButton::A);
+            }
+            return;
+        }
+
+        if let Some(button) = self.plan.as_mut().and_then(Iterator::next) {
+            return game.button_click(button);
+        }
+        let cursor = game.board().cursor();
+        self.plan = self
+            .player
+            .next_move(&View::new(game))
+            .map(|next| next.buttons(cursor));
+        if let Some(button) = self.plan.as_mut().and_then(Iterator::next) {
+            game.button_click(button);
+        }
+    }
+}
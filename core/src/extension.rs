@@ -0,0 +1,173 @@
+use embedded_hal::blocking::i2c::{Read, Write};
+
+const ADDRESS: u8 = 0x52;
+const REPORT_SIZE: usize = 6;
+
+#[derive(Debug)]
+pub enum ExtensionError<E> {
+    Bus(E),
+    InvalidReport,
+    UnsupportedDevice,
+}
+
+#[cfg(feature = "defmt")]
+impl<E> defmt::Format for ExtensionError<E> {
+    fn format(&self, f: defmt::Formatter) {
+        match self {
+            ExtensionError::Bus(_) => defmt::write!(f, "bus error"),
+            ExtensionError::InvalidReport => defmt::write!(f, "invalid report"),
+            ExtensionError::UnsupportedDevice => defmt::write!(f, "unsupported device"),
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ExtensionKind {
+    Nunchuk,
+    Classic,
+}
+
+pub enum Reading {
+    Nunchuk(NunchukReading),
+    Classic(ClassicReading),
+}
+
+#[derive(Copy, Clone, Default)]
+pub struct NunchukReading {
+    pub joystick_x: u8,
+    pub joystick_y: u8,
+    pub accel_x: u16,
+    pub accel_y: u16,
+    pub accel_z: u16,
+    pub button_c: bool,
+    pub button_z: bool,
+}
+
+impl From<&[u8; REPORT_SIZE]> for NunchukReading {
+    fn from(report: &[u8; REPORT_SIZE]) -> Self {
+        let extra = report[5] as u16;
+        Self {
+            joystick_x: report[0],
+            joystick_y: report[1],
+            accel_x: (report[2] as u16) << 2 | (extra >> 2) & 0b11,
+            accel_y: (report[3] as u16) << 2 | (extra >> 4) & 0b11,
+            accel_z: (report[4] as u16) << 2 | (extra >> 6) & 0b11,
+            button_z: extra & 0b01 == 0,
+            button_c: extra & 0b10 == 0,
+        }
+    }
+}
+
+/// Buttons of a classic controller, one bit per button as in the report.
+#[derive(Copy, Clone, Default)]
+pub struct ClassicReading {
+    buttons: u16,
+}
+
+impl ClassicReading {
+    pub const DPAD_UP: u16 = 1 << 0;
+    pub const DPAD_LEFT: u16 = 1 << 1;
+    pub const BUTTON_ZR: u16 = 1 << 2;
+    pub const BUTTON_X: u16 = 1 << 3;
+    pub const BUTTON_A: u16 = 1 << 4;
+    pub const BUTTON_Y: u16 = 1 << 5;
+    pub const BUTTON_B: u16 = 1 << 6;
+    pub const BUTTON_ZL: u16 = 1 << 7;
+    pub const BUTTON_R: u16 = 1 << 9;
+    pub const BUTTON_PLUS: u16 = 1 << 10;
+    pub const BUTTON_HOME: u16 = 1 << 11;
+    pub const BUTTON_MINUS: u16 = 1 << 12;
+    pub const BUTTON_L: u16 = 1 << 13;
+    pub const DPAD_DOWN: u16 = 1 << 14;
+    pub const DPAD_RIGHT: u16 = 1 << 15;
+
+    /// Whether any of the buttons in `mask` is held.
+    pub fn any(&self, mask: u16) -> bool {
+        self.buttons & mask != 0
+    }
+}
+
+impl From<&[u8; REPORT_SIZE]> for ClassicReading {
+    fn from(report: &[u8; REPORT_SIZE]) -> Self {
+        // Buttons are active low
+        Self {
+            buttons: !(report[4] as u16) << 8 | !report[5] as u16,
+        }
+    }
+}
+
+pub struct Extension<I2C> {
+    bus: I2C,
+    kind: ExtensionKind,
+}
+
+impl<I2C, E> Extension<I2C>
+where
+    I2C: Read<Error = E> + Write<Error = E>,
+{
+    pub fn new(bus: I2C) -> Self {
+        Self {
+            bus,
+            kind: ExtensionKind::Nunchuk,
+        }
+    }
+
+    pub fn kind(&self) -> ExtensionKind {
+        self.kind
+    }
+
+    /// Starts the device and asks for its id, which needs time to settle
+    /// before `identify` reads it back.
+    pub fn init(&mut self) -> Result<(), ExtensionError<E>> {
+        // Unencrypted init sequence, works for both genuine and third party devices
+        self.write(&[0xf0, 0x55])?;
+        self.write(&[0xfb, 0x00])?;
+        self.write(&[0xfa])
+    }
+
+    pub fn identify(&mut self) -> Result<ExtensionKind, ExtensionError<E>> {
+        let mut id = [0; REPORT_SIZE];
+        self.bus
+            .read(ADDRESS, &mut id)
+            .map_err(ExtensionError::Bus)?;
+        self.kind = match id {
+            [_, _, 0xa4, 0x20, _, 0x00] => ExtensionKind::Nunchuk,
+            [_, _, 0xa4, 0x20, _, 0x01] => ExtensionKind::Classic,
+            _ => return Err(ExtensionError::UnsupportedDevice),
+        };
+        self.start_sample()?;
+        Ok(self.kind)
+    }
+
+    pub fn read(&mut self) -> Result<Reading, ExtensionError<E>> {
+        let report = self.read_report()?;
+        let reading = match self.kind {
+            ExtensionKind::Nunchuk => Reading::Nunchuk(NunchukReading::from(&report)),
+            ExtensionKind::Classic => Reading::Classic(ClassicReading::from(&report)),
+        };
+        Ok(reading)
+    }
+
+    fn read_report(&mut self) -> Result<[u8; REPORT_SIZE], ExtensionError<E>> {
+        let mut report = [0; REPORT_SIZE];
+        self.bus
+            .read(ADDRESS, &mut report)
+            .map_err(ExtensionError::Bus)?;
+        self.start_sample()?;
+
+        // A floating bus reads back as all ones
+        if report.iter().all(|&byte| byte == 0xff) {
+            return Err(ExtensionError::InvalidReport);
+        }
+        Ok(report)
+    }
+
+    fn start_sample(&mut self) -> Result<(), ExtensionError<E>> {
+        self.write(&[0x00])
+    }
+
+    fn write(&mut self, bytes: &[u8]) -> Result<(), ExtensionError<E>> {
+        self.bus.write(ADDRESS, bytes).map_err(ExtensionError::Bus)
+    }
+}
@@ -0,0 +1,609 @@
+use klaptik::*;
+
+use crate::board::*;
+use crate::sprites::*;
+use crate::text::*;
+use crate::tiles::*;
+
+#[derive(PartialEq, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum GameButton {
+    DPad(Dir),
+    A,
+    B,
+    Chord,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Dir {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+#[derive(Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Pause {
+    Disconnected,
+    Calibration,
+}
+
+/// Notable moves, picked up by feedback such as sound effects.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum GameEvent {
+    Reveal,
+    Flag,
+    Chord,
+    Explosion,
+    Win,
+}
+
+#[derive(Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum GameStatus {
+    Win,
+    Bootstrap,
+    Playing,
+    GameOver,
+}
+
+/// Board dimensions and mine count for a single game.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct GameConfig {
+    pub width: usize,
+    pub height: usize,
+    pub bombs: usize,
+    pub tiles: TileSize,
+}
+
+pub struct Minesweeper {
+    board: Board,
+    status: GameStatus,
+    config: GameConfig,
+    rng_seed: u32,
+    /// Whether the next board was given a seed, see `seed_entropy`.
+    seed_picked: bool,
+    board_seed: u32,
+    pause: Option<Pause>,
+    ticks: u16,
+    three_bv: u16,
+    clicks: u16,
+    revision: u32,
+    event: Option<GameEvent>,
+}
+
+impl Minesweeper {
+    pub const TICKS_PER_SECOND: u16 = 10;
+
+    pub fn new(config: GameConfig) -> Self {
+        assert!(config.bombs < config.width * config.height);
+        let mut board = Board::new(config.width, config.height);
+        let (cols, rows) = config.tiles.window(config.width, config.height);
+        board.set_window(cols, rows);
+        Self {
+            config,
+            board,
+            status: GameStatus::Bootstrap,
+            rng_seed: 42,
+            seed_picked: false,
+            board_seed: 0,
+            pause: None,
+            ticks: 0,
+            three_bv: 0,
+            clicks: 0,
+            revision: 0,
+            event: None,
+        }
+    }
+
+    pub fn config(&self) -> GameConfig {
+        self.config
+    }
+
+    pub fn board(&self) -> &Board {
+        &self.board
+    }
+
+    pub fn status(&self) -> GameStatus {
+        self.status
+    }
+
+    pub fn in_progress(&self) -> bool {
+        matches!(self.status, GameStatus::Playing)
+    }
+
+    pub fn mines_left(&self) -> usize {
+        let flags = self
+            .board
+            .tiles()
+            .iter()
+            .filter(|tile| tile.status() == TileStatus::Flagged)
+            .count();
+        self.config.bombs.saturating_sub(flags)
+    }
+
+    pub fn elapsed_secs(&self) -> u16 {
+        self.ticks / Self::TICKS_PER_SECOND
+    }
+
+    /// Fewest clicks that clear the current board, known once it is dealt.
+    pub fn three_bv(&self) -> u16 {
+        self.three_bv
+    }
+
+    /// Opens, flags and chords made in the current game.
+    pub fn clicks(&self) -> u16 {
+        self.clicks
+    }
+
+    /// 3BV cleared per second of play, in hundredths.
+    pub fn three_bv_rate(&self) -> u32 {
+        let ticks = self.ticks.max(1) as u32;
+        self.three_bv as u32 * Self::TICKS_PER_SECOND as u32 * 100 / ticks
+    }
+
+    /// 3BV as a percentage of the clicks made, chording can push it past 100.
+    pub fn efficiency(&self) -> u32 {
+        self.three_bv as u32 * 100 / self.clicks.max(1) as u32
+    }
+
+    pub fn tick(&mut self) {
+        if self.pause.is_none() && self.in_progress() {
+            self.ticks = self.ticks.saturating_add(1);
+            if self.ticks.is_multiple_of(Self::TICKS_PER_SECOND) {
+                self.touch();
+            }
+        }
+    }
+
+    pub fn pause(&mut self, reason: Pause) {
+        self.pause = Some(reason);
+        self.touch();
+    }
+
+    pub fn resume(&mut self) {
+        self.pause = None;
+        self.touch();
+    }
+
+    pub fn paused(&self) -> Option<Pause> {
+        self.pause
+    }
+
+    /// Counter bumped whenever anything shown on screen changes.
+    pub fn revision(&self) -> u32 {
+        self.revision.wrapping_add(self.board.revision())
+    }
+
+    /// Takes the latest event not yet handled.
+    pub fn take_event(&mut self) -> Option<GameEvent> {
+        self.event.take()
+    }
+
+    /// Picks the seed the next board is dealt from.
+    pub fn seed_random(&mut self, seed: u32) {
+        // Zero is a fixed point of the generator and would stall bootstrap
+        self.rng_seed = (seed % 0x7fff_ffff).max(1);
+        self.seed_picked = true;
+    }
+
+    /// Seeds the next board from an entropy source such as the time of a
+    /// click, unless `seed_random` already picked its seed.
+    pub fn seed_entropy(&mut self, entropy: u32) {
+        if !self.seed_picked {
+            self.rng_seed = (entropy % 0x7fff_ffff).max(1);
+        }
+    }
+
+    /// Seed the current board was dealt from, seeding with it deals the same
+    /// board again.
+    pub fn board_seed(&self) -> u32 {
+        self.board_seed
+    }
+
+    pub fn restart(&mut self, config: GameConfig) {
+        assert!(config.bombs < config.width * config.height);
+        let (cols, rows) = config.tiles.window(config.width, config.height);
+        self.config = config;
+        self.board.resize(config.width, config.height);
+        self.board.set_window(cols, rows);
+        self.set_status(GameStatus::Bootstrap);
+    }
+
+    pub fn button_click(&mut self, button: GameButton) {
+        if self.pause.is_some() {
+            return;
+        }
+
+        let status = self.status;
+        let counted = match button {
+            GameButton::DPad(_) => false,
+            GameButton::A => matches!(status, GameStatus::Bootstrap | GameStatus::Playing),
+            _ => status == GameStatus::Playing,
+        };
+        let mut cursor = self.board.cursor();
+        let event = match button {
+            GameButton::A => match self.status {
+                GameStatus::Bootstrap => {
+                    self.bootstrap();
+                    self.open_tile(cursor);
+                    Some(GameEvent::Reveal)
+                }
+                GameStatus::Playing => {
+                    self.open_tile(cursor);
+                    self.refresh_game_state();
+                    Some(GameEvent::Reveal)
+                }
+                _ => {
+                    self.bootstrap();
+                    None
+                }
+            },
+            GameButton::B => {
+                let event = match self.board.tile_at(cursor).status() {
+                    TileStatus::Closed => {
+                        self.board.set_status_at(cursor, TileStatus::Flagged);
+                        Some(GameEvent::Flag)
+                    }
+                    TileStatus::Flagged => {
+                        self.board.set_status_at(cursor, TileStatus::Closed);
+                        Some(GameEvent::Flag)
+                    }
+                    _ => None,
+                };
+                self.refresh_game_state();
+                event
+            }
+            GameButton::Chord => {
+                if let GameStatus::Playing = self.status {
+                    self.chord(cursor);
+                    self.refresh_game_state();
+                    Some(GameEvent::Chord)
+                } else {
+                    None
+                }
+            }
+            GameButton::DPad(dir) => {
+                match dir {
+                    Dir::Left if cursor.x > 0 => {
+                        cursor = Point::new(cursor.x - 1, cursor.y);
+                    }
+                    Dir::Right if cursor.x + 1 < self.board.width() as i32 => {
+                        cursor = Point::new(cursor.x + 1, cursor.y);
+                    }
+                    Dir::Up if cursor.y > 0 => {
+                        cursor = Point::new(cursor.x, cursor.y - 1);
+                    }
+                    Dir::Down if cursor.y + 1 < self.board.height() as i32 => {
+                        cursor = Point::new(cursor.x, cursor.y + 1);
+                    }
+                    _ => {}
+                }
+                self.board.move_cursor(cursor);
+                None
+            }
+        };
+
+        let event = match self.status {
+            current if current == status => event,
+            GameStatus::GameOver => Some(GameEvent::Explosion),
+            GameStatus::Win => Some(GameEvent::Win),
+            _ => event,
+        };
+        if event.is_some() {
+            self.event = event;
+        }
+        if counted {
+            self.clicks = self.clicks.saturating_add(1);
+        }
+        log_debug!(
+            "{} at ({=i32}, {=i32}): {}, {}",
+            button,
+            cursor.x,
+            cursor.y,
+            event,
+            self.status
+        );
+    }
+
+    fn refresh_game_state(&mut self) {
+        if self
+            .board
+            .tiles()
+            .iter()
+            .any(|&tile| tile.status() == TileStatus::Opened && tile.content() == TileContent::Bomb)
+        {
+            self.set_status(GameStatus::GameOver);
+            return;
+        }
+
+        let win = self.board.tiles().iter().all(|&tile| {
+            matches!(
+                (tile.status(), tile.content()),
+                (TileStatus::Flagged, TileContent::Bomb) | (TileStatus::Opened, _)
+            )
+        });
+
+        if win {
+            self.set_status(GameStatus::Win);
+        }
+    }
+
+    /// Opens a tile and flood fills from empty ones, returns how many tiles opened.
+    fn open_tile(&mut self, origin: Point) -> usize {
+        if self.board.tile_at(origin).status() != TileStatus::Closed {
+            return 0;
+        }
+        self.board.set_status_at(origin, TileStatus::Opened);
+        if self.board.tile_at(origin).content() != TileContent::Hint(0) {
+            return 1;
+        }
+
+        // Recursive flood fill would overflow the stack on large boards,
+        // so empty tiles wait in a bit set of offsets instead
+        let mut pending = OffsetSet::default();
+        pending.insert(self.board.point_offset(origin));
+        let mut opened = 1;
+        while let Some(offset) = pending.pop() {
+            let pos = self.board.offset_point(offset);
+            for neighbor in self.board.neighbors(pos) {
+                let tile = self.board.tile_at(neighbor);
+                if tile.status() != TileStatus::Closed {
+                    continue;
+                }
+                self.board.set_status_at(neighbor, TileStatus::Opened);
+                opened += 1;
+                if tile.content() == TileContent::Hint(0) {
+                    pending.insert(self.board.point_offset(neighbor));
+                }
+            }
+        }
+        log_debug!(
+            "flood fill from ({=i32}, {=i32}) opened {=usize} tiles",
+            origin.x,
+            origin.y,
+            opened
+        );
+        opened
+    }
+
+    fn chord(&mut self, origin: Point) {
+        let tile = self.board.tile_at(origin);
+        if let (TileStatus::Opened, TileContent::Hint(hint)) = (tile.status(), tile.content()) {
+            let flags = self
+                .board
+                .neighbors(origin)
+                .filter(|&pos| self.board.tile_at(pos).status() == TileStatus::Flagged)
+                .count();
+            if flags == hint as usize {
+                for neighbor in self.board.neighbors(origin) {
+                    self.open_tile(neighbor);
+                }
+            }
+        }
+    }
+
+    fn bootstrap(&mut self) {
+        log_info!(
+            "game start: {=usize}x{=usize}, {=usize} mines, seed {=u32}",
+            self.config.width,
+            self.config.height,
+            self.config.bombs,
+            self.rng_seed
+        );
+        self.board.reset();
+        self.board_seed = self.rng_seed;
+        self.seed_picked = false;
+
+        // Mines depend on the seed alone so a board code deals the same board
+        // wherever the first click lands, apart from the mine moved below
+        for _ in 0..self.config.bombs {
+            let pos = self.random_free_tile();
+            self.board.set_content_at(pos, TileContent::Bomb);
+        }
+
+        // The first click is always safe: a mine under it moves to the next
+        // free tile the generator picks, the same one for every such click
+        let cursor = self.board.cursor();
+        let tiles = self.board.width() * self.board.height();
+        if self.board.tile_at(cursor).content() == TileContent::Bomb && self.config.bombs < tiles {
+            let pos = self.random_free_tile();
+            self.board.set_content_at(pos, TileContent::Bomb);
+            self.board.set_content_at(cursor, TileContent::Hint(0));
+        }
+
+        for x in 0..self.board.width() {
+            for y in 0..self.board.height() {
+                let pos = Point::new(x as i32, y as i32);
+
+                if let TileContent::Bomb = self.board.tile_at(pos).content() {
+                    continue;
+                }
+
+                let mut bombs = 0;
+                for neighbor in self.board.neighbors(pos) {
+                    if let TileContent::Bomb = self.board.tile_at(neighbor).content() {
+                        bombs += 1;
+                    }
+                }
+
+                self.board.set_content_at(pos, TileContent::Hint(bombs));
+            }
+        }
+
+        self.ticks = 0;
+        self.clicks = 0;
+        self.three_bv = self.board.three_bv() as u16;
+        self.set_status(GameStatus::Playing);
+    }
+
+    fn set_status(&mut self, status: GameStatus) {
+        if matches!(status, GameStatus::Win | GameStatus::GameOver) {
+            log_info!(
+                "game end: {} after {=u16}s, 3BV {=u16}, {=u16} clicks",
+                status,
+                self.elapsed_secs(),
+                self.three_bv,
+                self.clicks
+            );
+        }
+        self.status = status;
+        self.touch();
+    }
+
+    fn touch(&mut self) {
+        self.revision = self.revision.wrapping_add(1);
+    }
+
+    /// Draws tiles until one without a mine comes up.
+    fn random_free_tile(&mut self) -> Point {
+        loop {
+            let pos = Point::new(
+                self.gen_random(self.board.width() as u16),
+                self.gen_random(self.board.height() as u16),
+            );
+            if self.board.tile_at(pos).content() != TileContent::Bomb {
+                return pos;
+            }
+        }
+    }
+
+    fn gen_random(&mut self, up_to: u16) -> i32 {
+        // Park-Miller step. The product needs 46 bits, Schrage's method keeps
+        // it in 32 so the MCU does without a 64-bit division routine
+        const MODULUS: u32 = 0x7fff_ffff;
+        const MULTIPLIER: u32 = 16_807;
+        const QUOTIENT: u32 = MODULUS / MULTIPLIER;
+        const REMAINDER: u32 = MODULUS % MULTIPLIER;
+        let plus = MULTIPLIER * (self.rng_seed % QUOTIENT);
+        let minus = REMAINDER * (self.rng_seed / QUOTIENT);
+        self.rng_seed = if plus >= minus {
+            plus - minus
+        } else {
+            plus + MODULUS - minus
+        };
+        (self.rng_seed % up_to as u32) as i32
+    }
+}
+
+/// Shortcuts for poking at the game during development.
+#[cfg(feature = "debug-console")]
+impl Minesweeper {
+    /// Opens every tile without ending the game.
+    pub fn reveal_all(&mut self) {
+        for y in 0..self.board.height() {
+            for x in 0..self.board.width() {
+                self.board
+                    .set_status_at(Point::new(x as i32, y as i32), TileStatus::Opened);
+            }
+        }
+    }
+
+    /// Adds a mine to a game in progress and updates the hints around it.
+    pub fn place_mine(&mut self, pos: Point) -> bool {
+        if !self.in_progress() || !self.board.contains(pos) {
+            return false;
+        }
+        let tile = self.board.tile_at(pos);
+        if tile.status() == TileStatus::Opened || tile.content() == TileContent::Bomb {
+            return false;
+        }
+        self.board.set_content_at(pos, TileContent::Bomb);
+        self.config.bombs += 1;
+        for neighbor in self.board.neighbors(pos) {
+            if let TileContent::Hint(hint) = self.board.tile_at(neighbor).content() {
+                self.board
+                    .set_content_at(neighbor, TileContent::Hint(hint + 1));
+            }
+        }
+        self.three_bv = self.board.three_bv() as u16;
+        true
+    }
+
+    pub fn jump_cursor(&mut self, pos: Point) -> bool {
+        if !self.board.contains(pos) {
+            return false;
+        }
+        self.board.move_cursor(pos);
+        true
+    }
+
+    /// Ends a game in progress as won or lost.
+    pub fn force_status(&mut self, status: GameStatus) -> bool {
+        if !self.in_progress() {
+            return false;
+        }
+        self.set_status(status);
+        true
+    }
+}
+
+widget_mux!(
+    GameScreen<&Minesweeper>,
+    GameScreenNode::Board,
+    {
+        board: BoardView;
+        win: EndPopup, b'W';
+        game_over: EndPopup, b'L';
+    },
+    |mux: &mut GameScreen, state: &Minesweeper| {
+        let node = match state.status {
+            GameStatus::GameOver => GameScreenNode::GameOver,
+            GameStatus::Win => GameScreenNode::Win,
+            _ => GameScreenNode::Board,
+        };
+        mux.set_active(node);
+        mux.board.update(state);
+        mux.win.update(state);
+        mux.game_over.update(state);
+    }
+);
+
+/// Win or game over popup with the game's 3BV stats on the line below.
+pub struct EndPopup {
+    popup: GlyphIcon,
+    stats: Text<21>,
+}
+
+impl EndPopup {
+    pub fn new(glyph: Glyph) -> Self {
+        Self {
+            popup: GlyphIcon::new(POPUP, glyph, Point::new(24, 24)),
+            stats: Text::new(Point::new(1, 56)),
+        }
+    }
+}
+
+impl Widget<&Minesweeper> for EndPopup {
+    fn invalidate(&mut self) {
+        self.popup.invalidate();
+        self.stats.invalidate();
+    }
+
+    fn update(&mut self, game: &Minesweeper) {
+        self.stats.clear();
+        self.stats.print(0, "3BV");
+        self.stats.print_number(7, game.three_bv() as u32);
+        if game.status() == GameStatus::Win {
+            let rate = game.three_bv_rate();
+            self.stats.print_number(10, rate / 100);
+            self.stats.print(10, ".");
+            let cents = (rate % 100) as u8;
+            self.stats.print(11, [b'0' + cents / 10, b'0' + cents % 10]);
+            self.stats.print(13, "/s");
+            self.stats.print_number(20, game.efficiency());
+            self.stats.print(20, "%");
+        } else {
+            self.stats.print_number(13, game.clicks() as u32);
+            self.stats.print(14, "clicks");
+        }
+    }
+
+    fn render<C: Canvas>(&mut self, canvas: &mut C) {
+        self.popup.render(canvas);
+        self.stats.render(canvas);
+    }
+}
@@ -0,0 +1,134 @@
+/// Power state picked by the idle policy.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum PowerState {
+    /// Display on, controller polled at full rate.
+    Active,
+    /// Display blanked, controller polled at a slower rate.
+    Blank,
+    /// Display blanked, MCU stopped between controller polls.
+    Sleep,
+}
+
+impl PowerState {
+    pub fn poll_interval_ms(&self) -> u32 {
+        match self {
+            PowerState::Active => 100,
+            PowerState::Blank => 250,
+            PowerState::Sleep => 1_000,
+        }
+    }
+}
+
+/// How long without input before each power state kicks in.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct IdlePolicy {
+    pub blank_after_ms: u32,
+    pub sleep_after_ms: u32,
+    /// How long a remote command keeps the device out of sleep, which stops
+    /// the serial port along with the MCU.
+    pub remote_hold_ms: u32,
+}
+
+impl Default for IdlePolicy {
+    fn default() -> Self {
+        Self {
+            blank_after_ms: 60_000,
+            sleep_after_ms: 180_000,
+            remote_hold_ms: 1_800_000,
+        }
+    }
+}
+
+/// Idle state machine driven by an external millisecond clock.
+///
+/// The clock is only ever compared by difference, so it may wrap around.
+pub struct IdleMonitor {
+    policy: IdlePolicy,
+    state: PowerState,
+    last_activity: u32,
+    /// Input on this poll woke the device, the rest of it is dropped too.
+    waking: bool,
+    last_remote: Option<u32>,
+}
+
+impl IdleMonitor {
+    pub fn new(policy: IdlePolicy, now: u32) -> Self {
+        Self {
+            policy,
+            state: PowerState::Active,
+            last_activity: now,
+            waking: false,
+            last_remote: None,
+        }
+    }
+
+    pub fn state(&self) -> PowerState {
+        self.state
+    }
+
+    /// Records user input, returns the new state if it changed.
+    pub fn activity(&mut self, now: u32) -> Option<PowerState> {
+        self.last_activity = now;
+        self.transition(PowerState::Active)
+    }
+
+    /// Records a remote command, which counts as user input too. Returns the
+    /// new state if it changed.
+    pub fn remote(&mut self, now: u32) -> Option<PowerState> {
+        self.last_remote = Some(now);
+        self.activity(now)
+    }
+
+    /// Records user input and runs it, unless it only wakes the device up.
+    /// Returns the new state if it woke up.
+    pub fn input(&mut self, now: u32, input: impl FnOnce()) -> Option<PowerState> {
+        let woke = self.activity(now);
+        self.waking |= woke.is_some();
+        if !self.waking {
+            input();
+        }
+        woke
+    }
+
+    /// Runs a poll of the input timer: advances `uptime` by the poll interval
+    /// and the policy with it, then `tick` unless the screen is blank.
+    /// Returns the new state if it changed.
+    pub fn poll(&mut self, uptime: &mut u32, tick: impl FnOnce()) -> Option<PowerState> {
+        self.waking = false;
+        *uptime = uptime.wrapping_add(self.state.poll_interval_ms());
+        let changed = self.update(*uptime);
+        if self.state == PowerState::Active {
+            tick();
+        }
+        changed
+    }
+
+    /// Milliseconds since the last user input.
+    pub fn idle_ms(&self, now: u32) -> u32 {
+        now.wrapping_sub(self.last_activity)
+    }
+
+    /// Advances the policy, returns the new state if it changed.
+    pub fn update(&mut self, now: u32) -> Option<PowerState> {
+        let idle = self.idle_ms(now);
+        let hold = self.policy.remote_hold_ms;
+        // Dropped once expired, so the clock wrapping around cannot bring it back
+        self.last_remote = self.last_remote.filter(|&at| now.wrapping_sub(at) < hold);
+        let state = if idle >= self.policy.sleep_after_ms && self.last_remote.is_none() {
+            PowerState::Sleep
+        } else if idle >= self.policy.blank_after_ms {
+            PowerState::Blank
+        } else {
+            PowerState::Active
+        };
+        self.transition(state)
+    }
+
+    fn transition(&mut self, state: PowerState) -> Option<PowerState> {
+        if state == self.state {
+            return None;
+        }
+        self.state = state;
+        Some(state)
+    }
+}
@@ -0,0 +1,351 @@
+use crate::board::TileStatus;
+use crate::extension::*;
+use crate::game::{Dir, GameStatus, Minesweeper};
+use crate::settings::*;
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Action {
+    Move(Dir),
+    Open,
+    Flag,
+    Chord,
+    Menu,
+}
+
+#[derive(Default)]
+pub struct InputMapper {
+    tilt: TiltTracker,
+    /// Dedicated chord and menu buttons held on the last reading.
+    held: u8,
+    /// Open and flag buttons pressed since both were last released.
+    gesture: u8,
+    combo_ticks: u8,
+}
+
+impl InputMapper {
+    const OPEN: u8 = 0b0001;
+    const FLAG: u8 = 0b0010;
+    const CHORD: u8 = 0b0100;
+    const MENU: u8 = 0b1000;
+    /// The buttons of the current gesture were used up elsewhere.
+    const SWALLOWED: u8 = 0b1_0000;
+    const MENU_HOLD_TICKS: u8 = 10;
+
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Drops the open and flag buttons held now, so their release does nothing.
+    pub fn swallow(&mut self) {
+        self.gesture |= Self::SWALLOWED;
+    }
+
+    pub fn map(&mut self, settings: &Settings, reading: &Reading, mut emit: impl FnMut(Action)) {
+        self.map_dyn(settings, reading, &mut emit)
+    }
+
+    /// Body of `map`, behind a trait object so each caller's closure does not
+    /// get its own copy in flash.
+    fn map_dyn(&mut self, settings: &Settings, reading: &Reading, emit: &mut dyn FnMut(Action)) {
+        let map = &settings.action_map;
+        let mut emit_move = |dir| emit(Action::Move(remap(map, dir)));
+
+        let (open, flag, chord, menu) = match reading {
+            Reading::Nunchuk(nunchuk) => {
+                let moves = match settings.control_mode {
+                    ControlMode::Stick => stick_directions(settings, nunchuk),
+                    ControlMode::Tilt => self.tilt.directions(settings, nunchuk),
+                };
+                moves.iter().flatten().for_each(|dir| emit_move(*dir));
+
+                // Holding both buttons opens the menu
+                if nunchuk.button_z && nunchuk.button_c {
+                    self.combo_ticks = self.combo_ticks.saturating_add(1);
+                } else {
+                    self.combo_ticks = 0;
+                }
+                let menu = self.combo_ticks == Self::MENU_HOLD_TICKS;
+
+                (nunchuk.button_z, nunchuk.button_c, false, menu)
+            }
+            Reading::Classic(classic) => {
+                type C = ClassicReading;
+                let (dpad, open, flag) = if map.left_handed {
+                    // Mirrored layout: face buttons move, D-pad acts
+                    let dpad = [C::BUTTON_X, C::BUTTON_A, C::BUTTON_B, C::BUTTON_Y];
+                    (dpad, C::DPAD_LEFT, C::DPAD_DOWN)
+                } else {
+                    let dpad = [C::DPAD_UP, C::DPAD_RIGHT, C::DPAD_DOWN, C::DPAD_LEFT];
+                    (dpad, C::BUTTON_A, C::BUTTON_B)
+                };
+                let dirs = [Dir::Up, Dir::Right, Dir::Down, Dir::Left];
+                for (&button, dir) in dpad.iter().zip(dirs) {
+                    if classic.any(button) {
+                        emit_move(dir);
+                    }
+                }
+
+                let chord = classic.any(C::BUTTON_R | C::BUTTON_ZR);
+                let menu =
+                    classic.any(C::BUTTON_L | C::BUTTON_ZL | C::BUTTON_PLUS | C::BUTTON_HOME);
+
+                (classic.any(open), classic.any(flag), chord, menu)
+            }
+        };
+
+        let (open, flag) = if map.swap_buttons {
+            (flag, open)
+        } else {
+            (open, flag)
+        };
+        // The menu takes over whatever open and flag were doing
+        if menu {
+            self.swallow();
+        }
+
+        // Open and flag act once both are up again, so a chord pressed or let
+        // go a tick apart is still a single chord. A button the chord is mapped
+        // to is resolved against the board by `resolve_chord`
+        let down = if open { Self::OPEN } else { 0 } | if flag { Self::FLAG } else { 0 };
+        if down != 0 {
+            self.gesture |= down;
+        } else {
+            let both = Self::OPEN | Self::FLAG;
+            let action = match core::mem::take(&mut self.gesture) {
+                Self::OPEN => Some(Action::Open),
+                Self::FLAG => Some(Action::Flag),
+                gesture if gesture == both && map.chord == ChordButton::Both => Some(Action::Chord),
+                _ => None,
+            };
+            if let Some(action) = action {
+                emit(action);
+            }
+        }
+
+        // Dedicated buttons fire once per press, movement repeats while held
+        let pressed = if chord { Self::CHORD } else { 0 } | if menu { Self::MENU } else { 0 };
+        let edges = pressed & !self.held;
+        self.held = pressed;
+        for (button, action) in [(Self::CHORD, Action::Chord), (Self::MENU, Action::Menu)].iter() {
+            if edges & button != 0 {
+                emit(*action);
+            }
+        }
+    }
+}
+
+/// Turns a press of the button chords are mapped to into a chord on an opened
+/// tile, so it counts as a single click either way.
+pub fn resolve_chord(settings: &Settings, action: Action, game: &Minesweeper) -> Action {
+    let chords = match settings.action_map.chord {
+        ChordButton::Both => false,
+        ChordButton::Open => action == Action::Open,
+        ChordButton::Flag => action == Action::Flag,
+    };
+    let board = game.board();
+    let opened = board.tile_at(board.cursor()).status() == TileStatus::Opened;
+    if chords && opened && game.status() == GameStatus::Playing {
+        return Action::Chord;
+    }
+    action
+}
+
+fn remap(map: &ActionMap, dir: Dir) -> Dir {
+    match dir {
+        Dir::Left if map.invert_x => Dir::Right,
+        Dir::Right if map.invert_x => Dir::Left,
+        Dir::Up if map.invert_y => Dir::Down,
+        Dir::Down if map.invert_y => Dir::Up,
+        dir => dir,
+    }
+}
+
+pub fn stick_directions(settings: &Settings, reading: &NunchukReading) -> [Option<Dir>; 2] {
+    let x = settings.x_axis.deflection(reading.joystick_x) as i16;
+    let y = settings.y_axis.deflection(reading.joystick_y) as i16;
+    let dead_zone = settings.dead_zone as i16;
+
+    let horizontal = match x {
+        x if x > dead_zone => Some(Dir::Right),
+        x if x < -dead_zone => Some(Dir::Left),
+        _ => None,
+    };
+    let vertical = match y {
+        y if y > dead_zone => Some(Dir::Up),
+        y if y < -dead_zone => Some(Dir::Down),
+        _ => None,
+    };
+
+    combine(settings, (horizontal, x), (vertical, y))
+}
+
+fn combine(
+    settings: &Settings,
+    horizontal: (Option<Dir>, i16),
+    vertical: (Option<Dir>, i16),
+) -> [Option<Dir>; 2] {
+    match (horizontal, vertical) {
+        ((Some(_), x), (Some(_), y)) if !settings.diagonal && x.abs() >= y.abs() => {
+            [horizontal.0, None]
+        }
+        ((Some(_), _), (Some(_), _)) if !settings.diagonal => [None, vertical.0],
+        _ => [horizontal.0, vertical.0],
+    }
+}
+
+#[derive(Default)]
+struct TiltAxis {
+    engaged: bool,
+    progress: u16,
+}
+
+impl TiltAxis {
+    const CENTER: i16 = 512;
+    const ENGAGE: i16 = 60;
+    const RELEASE: i16 = 40;
+    const STEP: u16 = 240;
+
+    /// Returns signed tilt when the axis should step the cursor, zero otherwise.
+    fn update(&mut self, accel: u16) -> i16 {
+        let tilt = accel as i16 - Self::CENTER;
+        let magnitude = tilt.abs();
+
+        if !self.engaged {
+            if magnitude < Self::ENGAGE {
+                return 0;
+            }
+            self.engaged = true;
+            self.progress = 0;
+            return tilt;
+        }
+
+        if magnitude < Self::RELEASE {
+            self.engaged = false;
+            return 0;
+        }
+
+        // Cursor rate grows with the tilt angle
+        self.progress += (magnitude - Self::RELEASE) as u16;
+        if self.progress < Self::STEP {
+            return 0;
+        }
+        self.progress -= Self::STEP;
+        tilt
+    }
+}
+
+#[derive(Default)]
+pub struct TiltTracker {
+    x: TiltAxis,
+    y: TiltAxis,
+}
+
+impl TiltTracker {
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn directions(
+        &mut self,
+        settings: &Settings,
+        reading: &NunchukReading,
+    ) -> [Option<Dir>; 2] {
+        let x = self.x.update(reading.accel_x);
+        let y = self.y.update(reading.accel_y);
+
+        let horizontal = match x {
+            x if x > 0 => Some(Dir::Right),
+            x if x < 0 => Some(Dir::Left),
+            _ => None,
+        };
+        // Pitching the controller forward lowers the Y reading
+        let vertical = match y {
+            y if y < 0 => Some(Dir::Up),
+            y if y > 0 => Some(Dir::Down),
+            _ => None,
+        };
+
+        combine(settings, (horizontal, x), (vertical, y))
+    }
+}
+
+enum CalibrationPhase {
+    Release,
+    Center { samples: u8, x: u16, y: u16 },
+    Extents,
+}
+
+pub struct Calibrator {
+    phase: CalibrationPhase,
+    center: (u8, u8),
+    min: (u8, u8),
+    max: (u8, u8),
+}
+
+impl Default for Calibrator {
+    fn default() -> Self {
+        Self {
+            phase: CalibrationPhase::Release,
+            center: (127, 127),
+            min: (u8::MAX, u8::MAX),
+            max: (0, 0),
+        }
+    }
+}
+
+impl Calibrator {
+    const CENTER_SAMPLES: u8 = 8;
+    /// Narrowest reach worth calibrating to, a genuine stick reaches about 100.
+    const MIN_REACH: u8 = 32;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feeds a controller reading, returns `true` once calibration is complete.
+    pub fn sample(&mut self, reading: &NunchukReading) -> bool {
+        let pos = (reading.joystick_x, reading.joystick_y);
+        match self.phase {
+            CalibrationPhase::Release if !reading.button_c && !reading.button_z => {
+                self.phase = CalibrationPhase::Center {
+                    samples: 0,
+                    x: 0,
+                    y: 0,
+                };
+            }
+            CalibrationPhase::Release => {}
+            CalibrationPhase::Center { samples, x, y } => {
+                let (x, y) = (x + pos.0 as u16, y + pos.1 as u16);
+                let samples = samples + 1;
+                if samples < Self::CENTER_SAMPLES {
+                    self.phase = CalibrationPhase::Center { samples, x, y };
+                } else {
+                    let samples = samples as u16;
+                    self.center = ((x / samples) as u8, (y / samples) as u8);
+                    self.phase = CalibrationPhase::Extents;
+                }
+            }
+            CalibrationPhase::Extents if reading.button_z => return true,
+            CalibrationPhase::Extents => {
+                self.min = (self.min.0.min(pos.0), self.min.1.min(pos.1));
+                self.max = (self.max.0.max(pos.0), self.max.1.max(pos.1));
+            }
+        }
+        false
+    }
+
+    /// Stores the calibration, an axis the stick was barely moved along keeps
+    /// the default instead of turning twitchy.
+    pub fn apply(&self, settings: &mut Settings) {
+        settings.x_axis = Self::axis(self.center.0, self.min.0, self.max.0);
+        settings.y_axis = Self::axis(self.center.1, self.min.1, self.max.1);
+    }
+
+    fn axis(center: u8, min: u8, max: u8) -> AxisCalibration {
+        let axis = AxisCalibration::from_extents(center, min, max);
+        if axis.threshold < Self::MIN_REACH {
+            return AxisCalibration::default();
+        }
+        axis
+    }
+}
@@ -0,0 +1,29 @@
+//! Hardware-independent part of the game: rules, input mapping, menus and UI.
+#![no_std]
+
+#[macro_use]
+mod log;
+
+pub mod board;
+pub mod challenge;
+#[cfg(feature = "debug-console")]
+pub mod console;
+pub mod controller;
+pub mod demo;
+pub mod extension;
+pub mod game;
+pub mod idle;
+pub mod input;
+pub mod line;
+pub mod menu;
+pub mod player;
+pub mod remote;
+pub mod settings;
+pub mod solver;
+pub mod sound;
+pub mod sprites;
+pub mod stats;
+pub mod text;
+pub mod tiles;
+pub mod ui;
+pub mod viewport;
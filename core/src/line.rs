@@ -0,0 +1,71 @@
+/// Splits a byte stream into trimmed lines of up to `LEN` bytes.
+pub struct LineBuffer<const LEN: usize> {
+    line: [u8; LEN],
+    len: usize,
+    overflow: bool,
+}
+
+/// Line did not fit into the buffer and was dropped.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct LineTooLong;
+
+impl<const LEN: usize> LineBuffer<LEN> {
+    pub fn new() -> Self {
+        Self {
+            line: [0; LEN],
+            len: 0,
+            overflow: false,
+        }
+    }
+
+    /// Takes the next byte, returns the line once it is complete and not blank.
+    pub fn push(&mut self, byte: u8) -> Option<Result<&[u8], LineTooLong>> {
+        match byte {
+            b'\r' => None,
+            b'\n' => {
+                let len = core::mem::replace(&mut self.len, 0);
+                if core::mem::replace(&mut self.overflow, false) {
+                    return Some(Err(LineTooLong));
+                }
+                // Lines stay bytes, checking UTF-8 would cost more flash than
+                // the ASCII commands need
+                match self.line[..len].trim_ascii() {
+                    [] => None,
+                    line => Some(Ok(line)),
+                }
+            }
+            _ if self.len == LEN => {
+                self.overflow = true;
+                None
+            }
+            _ => {
+                self.line[self.len] = byte;
+                self.len += 1;
+                None
+            }
+        }
+    }
+}
+
+impl<const LEN: usize> Default for LineBuffer<LEN> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Words of a line, split on ASCII whitespace.
+pub fn words(line: &[u8]) -> impl Iterator<Item = &[u8]> {
+    line.split(u8::is_ascii_whitespace)
+        .filter(|word| !word.is_empty())
+}
+
+/// Reads a decimal number, `None` unless the word is all digits and fits.
+pub fn number(word: &[u8]) -> Option<u32> {
+    if word.is_empty() {
+        return None;
+    }
+    word.iter().try_fold(0u32, |value, &byte| {
+        let digit = (byte as char).to_digit(10)?;
+        value.checked_mul(10)?.checked_add(digit)
+    })
+}
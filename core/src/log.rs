@@ -0,0 +1,22 @@
+//! defmt logging, compiled in only down to the level picked by the `log-*` features.
+
+macro_rules! log_warn {
+    ($($arg:tt)+) => {
+        #[cfg(feature = "log-warn")]
+        defmt::warn!($($arg)+);
+    };
+}
+
+macro_rules! log_info {
+    ($($arg:tt)+) => {
+        #[cfg(feature = "log-info")]
+        defmt::info!($($arg)+);
+    };
+}
+
+macro_rules! log_debug {
+    ($($arg:tt)+) => {
+        #[cfg(feature = "log-debug")]
+        defmt::debug!($($arg)+);
+    };
+}
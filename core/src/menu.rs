@@ -0,0 +1,474 @@
+use crate::challenge::*;
+use crate::game::{Dir, GameStatus, Minesweeper};
+use crate::input::Action;
+use crate::settings::*;
+use crate::stats::Stats;
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum Screen {
+    Game,
+    Title,
+    Difficulty,
+    Settings,
+    Scores,
+    Stats,
+    Challenge,
+    CodeEntry,
+    About,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum StatKind {
+    Played,
+    Won,
+    Lost,
+    Streak,
+    BestStreak,
+    PlayTime,
+    AverageWin,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum MenuItem {
+    NewGame,
+    Continue,
+    Difficulty,
+    Settings,
+    Scores,
+    Stats,
+    Challenge,
+    About,
+    Level(Difficulty),
+    ControlMode,
+    DeadZone,
+    Diagonal,
+    SwapButtons,
+    InvertX,
+    InvertY,
+    LeftHanded,
+    ChordButton,
+    #[cfg(feature = "sound")]
+    Sound,
+    Calibrate,
+    StatsLevel,
+    Stat(StatKind),
+    ResetStats,
+    BoardCode,
+    Year,
+    Month,
+    Day,
+    PlayDaily,
+    EnterCode,
+    Credits(u8),
+}
+
+impl Screen {
+    pub fn items(&self) -> &'static [MenuItem] {
+        match self {
+            Screen::Game | Screen::Scores | Screen::CodeEntry => &[],
+            Screen::Title => &[
+                MenuItem::NewGame,
+                MenuItem::Continue,
+                MenuItem::Difficulty,
+                MenuItem::Settings,
+                MenuItem::Scores,
+                MenuItem::Stats,
+                MenuItem::Challenge,
+                MenuItem::About,
+            ],
+            Screen::Difficulty => &[
+                MenuItem::Level(Difficulty::Easy),
+                MenuItem::Level(Difficulty::Normal),
+                MenuItem::Level(Difficulty::Hard),
+            ],
+            Screen::Settings => &[
+                MenuItem::ControlMode,
+                MenuItem::DeadZone,
+                MenuItem::Diagonal,
+                MenuItem::SwapButtons,
+                MenuItem::InvertX,
+                MenuItem::InvertY,
+                MenuItem::LeftHanded,
+                MenuItem::ChordButton,
+                #[cfg(feature = "sound")]
+                MenuItem::Sound,
+                MenuItem::Calibrate,
+            ],
+            Screen::Stats => &[
+                MenuItem::StatsLevel,
+                MenuItem::Stat(StatKind::Played),
+                MenuItem::Stat(StatKind::Won),
+                MenuItem::Stat(StatKind::Lost),
+                MenuItem::Stat(StatKind::Streak),
+                MenuItem::Stat(StatKind::BestStreak),
+                MenuItem::Stat(StatKind::PlayTime),
+                MenuItem::Stat(StatKind::AverageWin),
+                MenuItem::ResetStats,
+            ],
+            Screen::Challenge => &[
+                MenuItem::BoardCode,
+                MenuItem::Year,
+                MenuItem::Month,
+                MenuItem::Day,
+                MenuItem::PlayDaily,
+                MenuItem::EnterCode,
+            ],
+            Screen::About => &[
+                MenuItem::Credits(0),
+                MenuItem::Credits(1),
+                MenuItem::Credits(2),
+                MenuItem::Credits(3),
+                MenuItem::Credits(4),
+            ],
+        }
+    }
+
+    pub fn selectable(&self) -> bool {
+        !matches!(
+            self,
+            Screen::Game | Screen::Scores | Screen::CodeEntry | Screen::About
+        )
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum MenuEvent {
+    None,
+    NewGame,
+    Continue,
+    SaveSettings,
+    Calibrate,
+    ResetStats,
+    /// The challenge screen opened, a chance to fill in today's date.
+    Challenge,
+    PlayDaily,
+    PlayCode(BoardCode),
+}
+
+pub struct Menu {
+    stack: [Screen; Self::DEPTH],
+    selection: [usize; Self::DEPTH],
+    depth: usize,
+    game_difficulty: Difficulty,
+    best_times: [Option<u16>; 3],
+    stats_level: Difficulty,
+    confirm_reset: bool,
+    date: Date,
+    code: [u8; BoardCode::SYMBOLS],
+    code_cursor: usize,
+    code_error: bool,
+    revision: u32,
+}
+
+impl Default for Menu {
+    fn default() -> Self {
+        Self {
+            stack: [Screen::Title; Self::DEPTH],
+            selection: [0; Self::DEPTH],
+            depth: 1,
+            game_difficulty: Difficulty::Easy,
+            best_times: [None; 3],
+            stats_level: Difficulty::Easy,
+            confirm_reset: false,
+            date: Date::default(),
+            code: [0; BoardCode::SYMBOLS],
+            code_cursor: 0,
+            code_error: false,
+            revision: 0,
+        }
+    }
+}
+
+impl Menu {
+    pub const ROWS: usize = 6;
+    const DEPTH: usize = 3;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn screen(&self) -> Screen {
+        self.stack[self.depth - 1]
+    }
+
+    pub fn selection(&self) -> usize {
+        self.selection[self.depth - 1]
+    }
+
+    /// Items currently on screen, paired with their selection state.
+    pub fn visible_items(&self) -> impl Iterator<Item = (MenuItem, bool)> + '_ {
+        let screen = self.screen();
+        let selection = self.selection();
+        let scroll = selection.saturating_sub(Self::ROWS - 1);
+        screen
+            .items()
+            .iter()
+            .enumerate()
+            .skip(scroll)
+            .take(Self::ROWS)
+            .map(move |(idx, item)| (*item, screen.selectable() && idx == selection))
+    }
+
+    /// Counter bumped whenever the menu changes.
+    pub fn revision(&self) -> u32 {
+        self.revision
+    }
+
+    pub fn open(&mut self) {
+        self.touch();
+        self.depth = 1;
+        self.stack[0] = Screen::Title;
+        self.selection[0] = 0;
+    }
+
+    pub fn start_game(&mut self, difficulty: Difficulty) {
+        self.game_difficulty = difficulty;
+        self.enter_game();
+    }
+
+    pub fn enter_game(&mut self) {
+        self.touch();
+        self.depth = 1;
+        self.stack[0] = Screen::Game;
+    }
+
+    /// Difficulty of the game started last.
+    pub fn game_difficulty(&self) -> Difficulty {
+        self.game_difficulty
+    }
+
+    /// Difficulty shown on the stats screen.
+    pub fn stats_level(&self) -> Difficulty {
+        self.stats_level
+    }
+
+    /// Whether the next press on reset wipes the stats.
+    pub fn confirming_reset(&self) -> bool {
+        self.confirm_reset
+    }
+
+    /// Date of the daily board.
+    pub fn date(&self) -> Date {
+        self.date
+    }
+
+    pub fn set_date(&mut self, date: Date) {
+        self.date = date;
+        self.touch();
+    }
+
+    /// Code being entered, as indices into `BoardCode::ALPHABET`.
+    pub fn code_symbols(&self) -> &[u8; BoardCode::SYMBOLS] {
+        &self.code
+    }
+
+    pub fn code_cursor(&self) -> usize {
+        self.code_cursor
+    }
+
+    /// Whether the last attempt to play the entered code failed.
+    pub fn code_error(&self) -> bool {
+        self.code_error
+    }
+
+    pub fn best_time(&self, difficulty: Difficulty) -> Option<u16> {
+        self.best_times[difficulty as usize]
+    }
+
+    pub fn record_win(&mut self, secs: u16) {
+        let best = &mut self.best_times[self.game_difficulty as usize];
+        if best.is_none_or(|best| secs < best) {
+            *best = Some(secs);
+            self.touch();
+        }
+    }
+
+    /// Runs a game button from any input, keeping stats and best times in step.
+    /// Returns whether it finished the game.
+    pub fn play(
+        &mut self,
+        game: &mut Minesweeper,
+        stats: &mut Stats,
+        action: impl FnOnce(&mut Minesweeper),
+    ) -> bool {
+        let before = game.status();
+        action(game);
+        let after = game.status();
+        // Only preset boards are ranked, the remote can deal any mine count
+        if let Some(difficulty) = Difficulty::from_config(&game.config()) {
+            self.game_difficulty = difficulty;
+            stats.record(difficulty, before, after, game.elapsed_secs());
+            if before == GameStatus::Playing && after == GameStatus::Win {
+                self.record_win(game.elapsed_secs());
+            }
+        }
+        before != after && matches!(after, GameStatus::Win | GameStatus::GameOver)
+    }
+
+    pub fn handle(&mut self, action: Action, settings: &mut Settings) -> MenuEvent {
+        let screen = self.screen();
+        let items = screen.items();
+        let level = self.depth - 1;
+        let confirming = core::mem::replace(&mut self.confirm_reset, false);
+        self.touch();
+        if screen == Screen::CodeEntry {
+            return self.edit_code(action);
+        }
+        match action {
+            Action::Move(Dir::Up) => {
+                self.selection[level] = self.selection[level].saturating_sub(1);
+                MenuEvent::None
+            }
+            Action::Move(Dir::Down) => {
+                let last = items.len().saturating_sub(1);
+                self.selection[level] = usize::min(self.selection[level] + 1, last);
+                MenuEvent::None
+            }
+            Action::Move(dir) if screen == Screen::Stats => {
+                self.stats_level = step_level(self.stats_level, dir == Dir::Right);
+                MenuEvent::None
+            }
+            Action::Move(dir) if screen == Screen::Settings => {
+                if items[self.selection[level]] == MenuItem::DeadZone {
+                    settings.step_dead_zone(dir == Dir::Right);
+                }
+                MenuEvent::None
+            }
+            Action::Move(dir) if screen == Screen::Challenge => {
+                self.step_date(items[self.selection[level]], dir == Dir::Right);
+                MenuEvent::None
+            }
+            Action::Open if screen.selectable() => match items[self.selection[level]] {
+                MenuItem::ResetStats if confirming => MenuEvent::ResetStats,
+                item => self.activate(item, settings),
+            },
+            Action::Flag | Action::Menu => self.back(),
+            _ => MenuEvent::None,
+        }
+    }
+
+    fn activate(&mut self, item: MenuItem, settings: &mut Settings) -> MenuEvent {
+        let map = &mut settings.action_map;
+        match item {
+            MenuItem::NewGame => return MenuEvent::NewGame,
+            MenuItem::Continue => return MenuEvent::Continue,
+            MenuItem::Difficulty => {
+                self.push(Screen::Difficulty);
+                self.selection[self.depth - 1] = settings.difficulty as usize;
+            }
+            MenuItem::Settings => self.push(Screen::Settings),
+            MenuItem::Scores => self.push(Screen::Scores),
+            MenuItem::Stats => {
+                self.push(Screen::Stats);
+                self.stats_level = settings.difficulty;
+            }
+            MenuItem::Challenge => {
+                self.push(Screen::Challenge);
+                return MenuEvent::Challenge;
+            }
+            MenuItem::About => self.push(Screen::About),
+            MenuItem::Level(difficulty) => {
+                settings.difficulty = difficulty;
+                return self.back();
+            }
+            MenuItem::ControlMode => {
+                settings.control_mode = match settings.control_mode {
+                    ControlMode::Stick => ControlMode::Tilt,
+                    ControlMode::Tilt => ControlMode::Stick,
+                }
+            }
+            MenuItem::DeadZone => settings.step_dead_zone(true),
+            MenuItem::Diagonal => settings.diagonal = !settings.diagonal,
+            MenuItem::SwapButtons => map.swap_buttons = !map.swap_buttons,
+            MenuItem::InvertX => map.invert_x = !map.invert_x,
+            MenuItem::InvertY => map.invert_y = !map.invert_y,
+            MenuItem::LeftHanded => map.left_handed = !map.left_handed,
+            MenuItem::ChordButton => {
+                map.chord = match map.chord {
+                    ChordButton::Both => ChordButton::Open,
+                    ChordButton::Open => ChordButton::Flag,
+                    ChordButton::Flag => ChordButton::Both,
+                }
+            }
+            #[cfg(feature = "sound")]
+            MenuItem::Sound => settings.sound = !settings.sound,
+            MenuItem::Calibrate => return MenuEvent::Calibrate,
+            MenuItem::StatsLevel => self.stats_level = step_level(self.stats_level, true),
+            MenuItem::ResetStats => self.confirm_reset = true,
+            MenuItem::Year | MenuItem::Month | MenuItem::Day => self.step_date(item, true),
+            MenuItem::PlayDaily => return MenuEvent::PlayDaily,
+            MenuItem::EnterCode => {
+                self.push(Screen::CodeEntry);
+                self.code_cursor = 0;
+                self.code_error = false;
+            }
+            MenuItem::Stat(_) | MenuItem::BoardCode | MenuItem::Credits(_) => {}
+        }
+        MenuEvent::None
+    }
+
+    fn step_date(&mut self, item: MenuItem, forward: bool) {
+        match item {
+            MenuItem::Year => self.date.step_year(forward),
+            MenuItem::Month => self.date.step_month(forward),
+            MenuItem::Day => self.date.step_day(forward),
+            _ => {}
+        }
+    }
+
+    /// Left and right pick a symbol, up and down change it.
+    fn edit_code(&mut self, action: Action) -> MenuEvent {
+        let last = BoardCode::SYMBOLS - 1;
+        let alphabet = BoardCode::ALPHABET.len() as u8;
+        match action {
+            Action::Move(Dir::Left) => self.code_cursor = self.code_cursor.saturating_sub(1),
+            Action::Move(Dir::Right) => self.code_cursor = usize::min(self.code_cursor + 1, last),
+            Action::Move(dir) => {
+                let symbol = &mut self.code[self.code_cursor];
+                *symbol = match dir {
+                    Dir::Up => (*symbol + 1) % alphabet,
+                    _ => (*symbol + alphabet - 1) % alphabet,
+                };
+                self.code_error = false;
+            }
+            Action::Open => match BoardCode::from_symbols(&self.code) {
+                Some(code) => return MenuEvent::PlayCode(code),
+                None => self.code_error = true,
+            },
+            Action::Flag | Action::Menu => return self.back(),
+            _ => {}
+        }
+        MenuEvent::None
+    }
+
+    fn back(&mut self) -> MenuEvent {
+        if self.depth == 1 {
+            return MenuEvent::Continue;
+        }
+
+        let screen = self.screen();
+        self.depth -= 1;
+        match screen {
+            Screen::Settings | Screen::Difficulty => MenuEvent::SaveSettings,
+            _ => MenuEvent::None,
+        }
+    }
+
+    fn touch(&mut self) {
+        self.revision = self.revision.wrapping_add(1);
+    }
+
+    fn push(&mut self, screen: Screen) {
+        if self.depth < Self::DEPTH {
+            self.stack[self.depth] = screen;
+            self.selection[self.depth] = 0;
+            self.depth += 1;
+        }
+    }
+}
+
+fn step_level(difficulty: Difficulty, forward: bool) -> Difficulty {
+    let count = Difficulty::ALL.len();
+    let step = if forward { 1 } else { count - 1 };
+    Difficulty::ALL[(difficulty as usize + step) % count]
+}
@@ -0,0 +1,140 @@
+//! Interface for automated players such as the demo bot.
+
+use klaptik::Point;
+
+use crate::board::*;
+use crate::game::*;
+
+/// What a player can see of a tile.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Cell {
+    Closed,
+    Flagged,
+    Open(u8),
+    Mine,
+}
+
+/// The game as shown on screen, without the hidden mine layout.
+pub struct View<'a> {
+    game: &'a Minesweeper,
+}
+
+impl<'a> View<'a> {
+    pub fn new(game: &'a Minesweeper) -> Self {
+        Self { game }
+    }
+
+    pub fn width(&self) -> usize {
+        self.game.board().width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.game.board().height()
+    }
+
+    pub fn status(&self) -> GameStatus {
+        self.game.status()
+    }
+
+    pub fn mines_left(&self) -> usize {
+        self.game.mines_left()
+    }
+
+    pub fn cursor(&self) -> Point {
+        self.game.board().cursor()
+    }
+
+    pub fn contains(&self, pos: Point) -> bool {
+        self.game.board().contains(pos)
+    }
+
+    pub fn neighbors(&self, pos: Point) -> Neighbors {
+        self.game.board().neighbors(pos)
+    }
+
+    pub fn cell(&self, pos: Point) -> Cell {
+        let tile = self.game.board().tile_at(pos);
+        match (tile.status(), tile.content()) {
+            (TileStatus::Closed, _) => Cell::Closed,
+            (TileStatus::Flagged, _) => Cell::Flagged,
+            (TileStatus::Opened, TileContent::Bomb) => Cell::Mine,
+            (TileStatus::Opened, TileContent::Hint(hint)) => Cell::Open(hint),
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Move {
+    Open(Point),
+    Flag(Point),
+    Chord(Point),
+}
+
+impl Move {
+    pub fn target(&self) -> Point {
+        match *self {
+            Move::Open(pos) | Move::Flag(pos) | Move::Chord(pos) => pos,
+        }
+    }
+
+    /// Buttons that walk the cursor from `cursor` to the target and make the move.
+    pub fn buttons(&self, cursor: Point) -> Buttons {
+        let action = match self {
+            Move::Open(_) => GameButton::A,
+            Move::Flag(_) => GameButton::B,
+            Move::Chord(_) => GameButton::Chord,
+        };
+        Buttons {
+            action: Some(action),
+            ..Buttons::walk(cursor, self.target())
+        }
+    }
+}
+
+pub struct Buttons {
+    cursor: Point,
+    target: Point,
+    action: Option<GameButton>,
+}
+
+impl Buttons {
+    /// Buttons that only walk the cursor from `cursor` to `target`.
+    pub fn walk(cursor: Point, target: Point) -> Self {
+        Self {
+            cursor,
+            target,
+            action: None,
+        }
+    }
+}
+
+impl Iterator for Buttons {
+    type Item = GameButton;
+
+    fn next(&mut self) -> Option<GameButton> {
+        let (cursor, target) = (self.cursor, self.target);
+        let dir = if cursor.x < target.x {
+            Dir::Right
+        } else if cursor.x > target.x {
+            Dir::Left
+        } else if cursor.y < target.y {
+            Dir::Down
+        } else if cursor.y > target.y {
+            Dir::Up
+        } else {
+            return self.action.take();
+        };
+        self.cursor = match dir {
+            Dir::Right => Point::new(cursor.x + 1, cursor.y),
+            Dir::Left => Point::new(cursor.x - 1, cursor.y),
+            Dir::Down => Point::new(cursor.x, cursor.y + 1),
+            Dir::Up => Point::new(cursor.x, cursor.y - 1),
+        };
+        Some(GameButton::DPad(dir))
+    }
+}
+
+pub trait Player {
+    /// Picks the next move from the visible board, `None` once the game is over.
+    fn next_move(&mut self, view: &View) -> Option<Move>;
+}
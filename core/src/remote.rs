@@ -0,0 +1,179 @@
+//! Line based remote control protocol, spoken over a serial port.
+//!
+//! Every command is one line of ASCII, answered by one or more lines ending
+//! in `ok` or a single `error <reason>` line:
+//!
+//! ```text
+//! up | down | left | right    move the cursor
+//! open | flag | chord         press a game button
+//! new <seed> <mines>          restart the current board size with a seed
+//! status                      status <state> <mines left> <secs> <x> <y>
+//! board                       board <width> <height>, then a line per row
+//! ```
+//!
+//! Board rows show closed tiles as `#`, flags as `F`, opened empty tiles as
+//! `.`, hints as digits and exploded mines as `*`.
+
+use core::fmt::{self, Write};
+
+use crate::board::*;
+use crate::game::*;
+use crate::line::*;
+use crate::text::decimal;
+
+pub enum Command {
+    Button(GameButton),
+    NewGame { seed: u32, mines: usize },
+    Status,
+    Board,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum RemoteError {
+    LineTooLong,
+    UnknownCommand,
+    InvalidArgument,
+}
+
+impl RemoteError {
+    fn reason(&self) -> &'static str {
+        match self {
+            RemoteError::LineTooLong => "line too long",
+            RemoteError::UnknownCommand => "unknown command",
+            RemoteError::InvalidArgument => "invalid argument",
+        }
+    }
+}
+
+impl Command {
+    pub fn parse(line: &[u8]) -> Result<Self, RemoteError> {
+        let mut words = words(line);
+        let name = words.next().ok_or(RemoteError::UnknownCommand)?;
+        let mut arg = || {
+            words
+                .next()
+                .and_then(number)
+                .ok_or(RemoteError::InvalidArgument)
+        };
+        let command = match name {
+            b"up" => Command::Button(GameButton::DPad(Dir::Up)),
+            b"down" => Command::Button(GameButton::DPad(Dir::Down)),
+            b"left" => Command::Button(GameButton::DPad(Dir::Left)),
+            b"right" => Command::Button(GameButton::DPad(Dir::Right)),
+            b"open" => Command::Button(GameButton::A),
+            b"flag" => Command::Button(GameButton::B),
+            b"chord" => Command::Button(GameButton::Chord),
+            b"new" => Command::NewGame {
+                seed: arg()?,
+                mines: arg()? as usize,
+            },
+            b"status" => Command::Status,
+            b"board" => Command::Board,
+            _ => return Err(RemoteError::UnknownCommand),
+        };
+        if words.next().is_some() {
+            return Err(RemoteError::InvalidArgument);
+        }
+        Ok(command)
+    }
+}
+
+/// Collects received bytes into lines and parses them into commands.
+#[derive(Default)]
+pub struct Remote {
+    line: LineBuffer<32>,
+}
+
+impl Remote {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Takes the next received byte, returns a command once a line is complete.
+    pub fn feed(&mut self, byte: u8) -> Option<Result<Command, RemoteError>> {
+        match self.line.push(byte)? {
+            Ok(line) => Some(Command::parse(line)),
+            Err(LineTooLong) => Some(Err(RemoteError::LineTooLong)),
+        }
+    }
+}
+
+/// Runs `command` on the game and writes the reply to `out`.
+pub fn execute(command: Command, game: &mut Minesweeper, out: &mut impl Write) -> fmt::Result {
+    match command {
+        Command::Button(button) => game.button_click(button),
+        Command::NewGame { seed, mines } => {
+            let config = GameConfig {
+                bombs: mines,
+                ..game.config()
+            };
+            if mines >= config.width * config.height {
+                return reply_error(RemoteError::InvalidArgument, out);
+            }
+            game.seed_random(seed);
+            game.restart(config);
+        }
+        Command::Status => {
+            let status = match game.status() {
+                GameStatus::Bootstrap => "ready",
+                GameStatus::Playing => "playing",
+                GameStatus::Win => "win",
+                GameStatus::GameOver => "lost",
+            };
+            let cursor = game.board().cursor();
+            out.write_str("status ")?;
+            out.write_str(status)?;
+            for value in [
+                game.mines_left() as u32,
+                game.elapsed_secs() as u32,
+                cursor.x as u32,
+                cursor.y as u32,
+            ] {
+                out.write_char(' ')?;
+                write_number(out, value)?;
+            }
+            out.write_char('\n')?;
+        }
+        Command::Board => write_board(game.board(), out)?,
+    }
+    out.write_str("ok\n")
+}
+
+pub fn reply_error(err: RemoteError, out: &mut impl Write) -> fmt::Result {
+    out.write_str("error ")?;
+    out.write_str(err.reason())?;
+    out.write_char('\n')
+}
+
+pub(crate) fn write_board(board: &Board, out: &mut impl Write) -> fmt::Result {
+    out.write_str("board ")?;
+    write_number(out, board.width() as u32)?;
+    out.write_char(' ')?;
+    write_number(out, board.height() as u32)?;
+    out.write_char('\n')?;
+    for (idx, tile) in board.tiles().iter().enumerate() {
+        out.write_char(tile_char(tile))?;
+        if (idx + 1) % board.width() == 0 {
+            out.write_char('\n')?;
+        }
+    }
+    Ok(())
+}
+
+fn tile_char(tile: &Tile) -> char {
+    match (tile.status(), tile.content()) {
+        (TileStatus::Closed, _) => '#',
+        (TileStatus::Flagged, _) => 'F',
+        (TileStatus::Opened, TileContent::Bomb) => '*',
+        (TileStatus::Opened, TileContent::Hint(0)) => '.',
+        (TileStatus::Opened, TileContent::Hint(hint)) => (b'0' + hint) as char,
+    }
+}
+
+/// Writes `value` in decimal without the formatting machinery of `write!`.
+fn write_number(out: &mut impl Write, value: u32) -> fmt::Result {
+    let mut digits = [0; 10];
+    decimal(value, &mut digits)
+        .iter()
+        .try_for_each(|&digit| out.write_char(digit as char))
+}
@@ -0,0 +1,239 @@
+use crate::game::GameConfig;
+use crate::tiles::TileSize;
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct AxisCalibration {
+    pub center: u8,
+    pub threshold: u8,
+}
+
+impl Default for AxisCalibration {
+    fn default() -> Self {
+        Self {
+            center: 127,
+            threshold: 128,
+        }
+    }
+}
+
+impl AxisCalibration {
+    pub const MIN_THRESHOLD: u8 = 16;
+
+    pub fn from_extents(center: u8, min: u8, max: u8) -> Self {
+        let reach = (max.saturating_sub(center)).min(center.saturating_sub(min));
+        Self {
+            center,
+            threshold: reach.max(Self::MIN_THRESHOLD),
+        }
+    }
+
+    /// Stick deflection in percent of the calibrated reach, clamped to -100..=100.
+    pub fn deflection(&self, value: u8) -> i8 {
+        let offset = value as i32 - self.center as i32;
+        (offset * 100 / self.threshold as i32).clamp(-100, 100) as i8
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum ControlMode {
+    Stick,
+    Tilt,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum Difficulty {
+    Easy,
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    pub const ALL: [Difficulty; 3] = [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard];
+
+    /// Presets in `Difficulty` order, a table is smaller than a match in flash.
+    const CONFIGS: [GameConfig; 3] = [
+        GameConfig {
+            width: 16,
+            height: 6,
+            bombs: 8,
+            tiles: TileSize::Large,
+        },
+        GameConfig {
+            width: 20,
+            height: 8,
+            bombs: 25,
+            tiles: TileSize::Medium,
+        },
+        GameConfig {
+            width: 30,
+            height: 16,
+            bombs: 99,
+            tiles: TileSize::Small,
+        },
+    ];
+
+    pub fn config(&self) -> GameConfig {
+        Self::CONFIGS[*self as usize]
+    }
+
+    /// Preset a config was made from, boards of any other size or mine count have none.
+    pub fn from_config(config: &GameConfig) -> Option<Difficulty> {
+        Self::ALL.iter().copied().find(|difficulty| {
+            let preset = difficulty.config();
+            (preset.width, preset.height, preset.bombs)
+                == (config.width, config.height, config.bombs)
+        })
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum ChordButton {
+    Both,
+    Open,
+    Flag,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct ActionMap {
+    pub swap_buttons: bool,
+    pub invert_x: bool,
+    pub invert_y: bool,
+    pub left_handed: bool,
+    pub chord: ChordButton,
+}
+
+impl Default for ActionMap {
+    fn default() -> Self {
+        Self {
+            swap_buttons: false,
+            invert_x: false,
+            invert_y: false,
+            left_handed: false,
+            chord: ChordButton::Both,
+        }
+    }
+}
+
+impl ActionMap {
+    fn flags(&self) -> u8 {
+        self.swap_buttons as u8
+            | (self.invert_x as u8) << 1
+            | (self.invert_y as u8) << 2
+            | (self.left_handed as u8) << 3
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct Settings {
+    pub x_axis: AxisCalibration,
+    pub y_axis: AxisCalibration,
+    pub dead_zone: u8,
+    pub diagonal: bool,
+    pub control_mode: ControlMode,
+    pub action_map: ActionMap,
+    pub difficulty: Difficulty,
+    pub sound: bool,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            x_axis: AxisCalibration::default(),
+            y_axis: AxisCalibration::default(),
+            dead_zone: 50,
+            diagonal: false,
+            control_mode: ControlMode::Stick,
+            action_map: ActionMap::default(),
+            difficulty: Difficulty::Easy,
+            sound: true,
+        }
+    }
+}
+
+impl Settings {
+    pub const RECORD_SIZE: usize = 16;
+    pub const DEAD_ZONE_STEP: u8 = 10;
+    pub const MAX_DEAD_ZONE: u8 = 90;
+
+    const MAGIC: u8 = 0x4d;
+    const VERSION: u8 = 6;
+
+    pub fn encode(&self) -> [u8; Self::RECORD_SIZE] {
+        let mut record = [0; Self::RECORD_SIZE];
+        record[0] = Self::MAGIC;
+        record[1] = Self::VERSION;
+        record[2] = self.x_axis.center;
+        record[3] = self.x_axis.threshold;
+        record[4] = self.y_axis.center;
+        record[5] = self.y_axis.threshold;
+        record[6] = self.dead_zone;
+        record[7] = self.diagonal as u8;
+        record[8] = self.control_mode as u8;
+        record[9] = self.action_map.flags();
+        record[10] = self.action_map.chord as u8;
+        record[11] = self.difficulty as u8;
+        record[12] = self.sound as u8;
+        record[Self::RECORD_SIZE - 1] = checksum(&record[..Self::RECORD_SIZE - 1]);
+        record
+    }
+
+    pub fn decode(record: &[u8; Self::RECORD_SIZE]) -> Option<Self> {
+        if record[0] != Self::MAGIC
+            || record[1] != Self::VERSION
+            || record[Self::RECORD_SIZE - 1] != checksum(&record[..Self::RECORD_SIZE - 1])
+        {
+            return None;
+        }
+
+        let axis = |center, threshold| AxisCalibration {
+            center,
+            threshold: u8::max(threshold, AxisCalibration::MIN_THRESHOLD),
+        };
+        Some(Self {
+            x_axis: axis(record[2], record[3]),
+            y_axis: axis(record[4], record[5]),
+            dead_zone: record[6].min(Self::MAX_DEAD_ZONE),
+            diagonal: record[7] != 0,
+            control_mode: match record[8] {
+                1 => ControlMode::Tilt,
+                _ => ControlMode::Stick,
+            },
+            action_map: ActionMap {
+                swap_buttons: record[9] & 0b0001 != 0,
+                invert_x: record[9] & 0b0010 != 0,
+                invert_y: record[9] & 0b0100 != 0,
+                left_handed: record[9] & 0b1000 != 0,
+                chord: match record[10] {
+                    1 => ChordButton::Open,
+                    2 => ChordButton::Flag,
+                    _ => ChordButton::Both,
+                },
+            },
+            difficulty: match record[11] {
+                1 => Difficulty::Normal,
+                2 => Difficulty::Hard,
+                _ => Difficulty::Easy,
+            },
+            sound: record[12] != 0,
+        })
+    }
+
+    /// Steps the stick dead zone, wrapping around at both ends.
+    pub fn step_dead_zone(&mut self, forward: bool) {
+        let (min, max) = (Self::DEAD_ZONE_STEP, Self::MAX_DEAD_ZONE);
+        let zone = self.dead_zone / Self::DEAD_ZONE_STEP * Self::DEAD_ZONE_STEP;
+        self.dead_zone = match forward {
+            true if zone >= max => min,
+            true => zone + Self::DEAD_ZONE_STEP,
+            false if zone <= min => max,
+            false => zone - Self::DEAD_ZONE_STEP,
+        };
+    }
+}
+
+/// Checksum closing each flash record, folded over the bytes before it.
+pub(crate) fn checksum(bytes: &[u8]) -> u8 {
+    bytes
+        .iter()
+        .fold(0, |acc: u8, byte| acc.rotate_left(1) ^ byte)
+}
@@ -0,0 +1,193 @@
+//! Bot that plays by the usual deduction rules and guesses only when stuck.
+
+use klaptik::Point;
+
+use crate::game::GameStatus;
+use crate::player::*;
+
+/// Closed tiles around an open hint and how many mines are still among them.
+struct Constraint {
+    unknown: [Point; 8],
+    len: usize,
+    mines: usize,
+}
+
+impl Constraint {
+    fn at(view: &View, pos: Point) -> Option<Self> {
+        let hint = match view.cell(pos) {
+            Cell::Open(hint) => hint as usize,
+            _ => return None,
+        };
+        let mut unknown = [Point::new(0, 0); 8];
+        let mut len = 0;
+        let mut flags = 0;
+        for neighbor in view.neighbors(pos) {
+            match view.cell(neighbor) {
+                Cell::Closed => {
+                    unknown[len] = neighbor;
+                    len += 1;
+                }
+                Cell::Flagged => flags += 1,
+                _ => {}
+            }
+        }
+        if len == 0 || flags > hint {
+            return None;
+        }
+        Some(Self {
+            unknown,
+            len,
+            mines: hint - flags,
+        })
+    }
+
+    fn unknown(&self) -> &[Point] {
+        &self.unknown[..self.len]
+    }
+
+    fn contains(&self, pos: Point) -> bool {
+        self.unknown().contains(&pos)
+    }
+
+    /// Whether every unknown tile of `self` is also unknown in `other`.
+    fn within(&self, other: &Constraint) -> bool {
+        self.unknown().iter().all(|&pos| other.contains(pos))
+    }
+}
+
+#[derive(Default)]
+pub struct Solver {
+    guesses: u32,
+}
+
+impl Solver {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Moves made without a safe deduction so far.
+    pub fn guesses(&self) -> u32 {
+        self.guesses
+    }
+
+    /// Moves following from a single hint: all its mines found, or all its tiles mines.
+    fn single(&self, view: &View) -> Option<Move> {
+        positions(view).find_map(|pos| {
+            let constraint = Constraint::at(view, pos)?;
+            if constraint.mines == 0 {
+                Some(Move::Chord(pos))
+            } else if constraint.mines == constraint.len {
+                Some(Move::Flag(constraint.unknown[0]))
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Moves following from one hint's tiles being a subset of a nearby hint's.
+    fn subset(&self, view: &View) -> Option<Move> {
+        positions(view).find_map(|pos| {
+            let inner = Constraint::at(view, pos)?;
+            // Hints up to two tiles away can share unknown tiles
+            (0..25)
+                .map(|idx| Point::new(pos.x + idx % 5 - 2, pos.y + idx / 5 - 2))
+                .filter(|&other| other != pos && view.contains(other))
+                .find_map(|other| {
+                    let outer = Constraint::at(view, other)?;
+                    if !inner.within(&outer) || outer.mines < inner.mines {
+                        return None;
+                    }
+                    let rest = outer.len - inner.len;
+                    let mines = outer.mines - inner.mines;
+                    let mut diff = outer.unknown().iter().filter(|&&pos| !inner.contains(pos));
+                    match diff.next() {
+                        Some(&pos) if mines == 0 => Some(Move::Open(pos)),
+                        Some(&pos) if mines == rest => Some(Move::Flag(pos)),
+                        _ => None,
+                    }
+                })
+        })
+    }
+
+    /// Moves following from the mine counter: every closed tile is a mine, or none is.
+    fn count(&self, view: &View) -> Option<Move> {
+        let mut closed = positions(view).filter(|&pos| view.cell(pos) == Cell::Closed);
+        let first = closed.next()?;
+        let mines = view.mines_left();
+        if mines == 0 {
+            Some(Move::Open(first))
+        } else if mines == 1 + closed.count() {
+            Some(Move::Flag(first))
+        } else {
+            None
+        }
+    }
+
+    /// Closed tile least likely to hide a mine, judged by nearby hints or the
+    /// overall mine density.
+    fn guess(&self, view: &View) -> Option<Move> {
+        let closed = positions(view)
+            .filter(|&pos| view.cell(pos) == Cell::Closed)
+            .count();
+        let density = (view.mines_left(), closed);
+
+        positions(view)
+            .filter(|&pos| view.cell(pos) == Cell::Closed)
+            .map(|pos| {
+                let risk = view
+                    .neighbors(pos)
+                    .filter_map(|hint| Constraint::at(view, hint))
+                    .map(|constraint| (constraint.mines, constraint.len))
+                    .fold(None, |worst: Option<Risk>, risk| match worst {
+                        Some(worst) if !riskier(risk, worst) => Some(worst),
+                        _ => Some(risk),
+                    })
+                    .unwrap_or(density);
+                (pos, risk)
+            })
+            .fold(
+                None,
+                |best: Option<(Point, Risk)>, (pos, risk)| match best {
+                    Some(best) if !riskier(best.1, risk) => Some(best),
+                    _ => Some((pos, risk)),
+                },
+            )
+            .map(|(pos, _)| Move::Open(pos))
+    }
+}
+
+impl Player for Solver {
+    fn next_move(&mut self, view: &View) -> Option<Move> {
+        match view.status() {
+            GameStatus::Bootstrap => {
+                let center = Point::new(view.width() as i32 / 2, view.height() as i32 / 2);
+                return Some(Move::Open(center));
+            }
+            GameStatus::Playing => {}
+            _ => return None,
+        }
+        let safe = self
+            .single(view)
+            .or_else(|| self.subset(view))
+            .or_else(|| self.count(view));
+        if let Some(next) = safe {
+            return Some(next);
+        }
+        self.guesses += 1;
+        self.guess(view)
+    }
+}
+
+/// Mines among a number of tiles.
+type Risk = (usize, usize);
+
+/// Compares `mines / tiles` ratios without dividing.
+fn riskier(a: Risk, b: Risk) -> bool {
+    a.0 * b.1 > b.0 * a.1
+}
+
+fn positions(view: &View) -> impl Iterator<Item = Point> {
+    let width = view.width();
+    (0..width * view.height())
+        .map(move |idx| Point::new((idx % width) as i32, (idx / width) as i32))
+}
@@ -0,0 +1,175 @@
+use crate::game::GameEvent;
+
+/// Tone of `freq` Hz (0 is a rest) lasting `ticks` sequencer ticks.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Note {
+    pub freq: u16,
+    pub ticks: u8,
+}
+
+const fn note(freq: u16, ticks: u8) -> Note {
+    Note { freq, ticks }
+}
+
+const REVEAL: &[Note] = &[note(1760, 2)];
+const FLAG: &[Note] = &[note(988, 3), note(1319, 3)];
+const CHORD: &[Note] = &[note(1047, 2), note(1319, 2), note(1568, 2)];
+const EXPLOSION: &[Note] = &[
+    note(440, 6),
+    note(370, 6),
+    note(311, 6),
+    note(262, 6),
+    note(220, 16),
+];
+const WIN: &[Note] = &[
+    note(523, 10),
+    note(659, 10),
+    note(784, 10),
+    note(0, 4),
+    note(1047, 24),
+];
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Effect {
+    Reveal,
+    Flag,
+    Chord,
+    Explosion,
+    Win,
+}
+
+impl Effect {
+    pub fn notes(&self) -> &'static [Note] {
+        match self {
+            Effect::Reveal => REVEAL,
+            Effect::Flag => FLAG,
+            Effect::Chord => CHORD,
+            Effect::Explosion => EXPLOSION,
+            Effect::Win => WIN,
+        }
+    }
+
+    /// Game ending effects cut off whatever is playing.
+    fn preempts(&self) -> bool {
+        matches!(self, Effect::Explosion | Effect::Win)
+    }
+}
+
+impl From<GameEvent> for Effect {
+    fn from(event: GameEvent) -> Self {
+        match event {
+            GameEvent::Reveal => Effect::Reveal,
+            GameEvent::Flag => Effect::Flag,
+            GameEvent::Chord => Effect::Chord,
+            GameEvent::Explosion => Effect::Explosion,
+            GameEvent::Win => Effect::Win,
+        }
+    }
+}
+
+/// Plays queued effects note by note, driven by a periodic tick.
+///
+/// The sequencer knows nothing about the buzzer: `tick` reports the
+/// frequency to output whenever it changes and the caller applies it.
+pub struct Sequencer {
+    queue: [Effect; Self::QUEUE],
+    head: usize,
+    len: usize,
+    playing: Option<Playing>,
+    output: u16,
+}
+
+#[derive(Copy, Clone)]
+struct Playing {
+    effect: Effect,
+    note: usize,
+    ticks_left: u8,
+}
+
+impl Default for Sequencer {
+    fn default() -> Self {
+        Self {
+            queue: [Effect::Reveal; Self::QUEUE],
+            head: 0,
+            len: 0,
+            playing: None,
+            output: 0,
+        }
+    }
+}
+
+impl Sequencer {
+    pub const TICK_MS: u32 = 10;
+    const QUEUE: usize = 4;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queues an effect, dropping it if the queue is full.
+    pub fn play(&mut self, effect: Effect) {
+        if effect.preempts() {
+            self.len = 0;
+            self.playing = None;
+        }
+        if self.len < Self::QUEUE {
+            self.queue[(self.head + self.len) % Self::QUEUE] = effect;
+            self.len += 1;
+        }
+    }
+
+    pub fn stop(&mut self) {
+        self.len = 0;
+        self.playing = None;
+    }
+
+    /// Advances by one tick. Returns the frequency to output when it
+    /// changes, 0 meaning silence.
+    pub fn tick(&mut self) -> Option<u16> {
+        let freq = self.advance();
+        if freq == self.output {
+            return None;
+        }
+        self.output = freq;
+        Some(freq)
+    }
+
+    fn advance(&mut self) -> u16 {
+        if let Some(playing) = self.playing.as_mut() {
+            let notes = playing.effect.notes();
+            if playing.ticks_left > 1 {
+                playing.ticks_left -= 1;
+                return notes[playing.note].freq;
+            }
+            playing.note += 1;
+            if let Some(note) = notes.get(playing.note) {
+                playing.ticks_left = note.ticks;
+                return note.freq;
+            }
+            self.playing = None;
+        }
+
+        match self.dequeue() {
+            Some(effect) => {
+                let note = effect.notes()[0];
+                self.playing = Some(Playing {
+                    effect,
+                    note: 0,
+                    ticks_left: note.ticks,
+                });
+                note.freq
+            }
+            None => 0,
+        }
+    }
+
+    fn dequeue(&mut self) -> Option<Effect> {
+        if self.len == 0 {
+            return None;
+        }
+        let effect = self.queue[self.head];
+        self.head = (self.head + 1) % Self::QUEUE;
+        self.len -= 1;
+        Some(effect)
+    }
+}
@@ -0,0 +1,197 @@
+use klaptik::*;
+
+use crate::tiles::TileSet;
+
+pub const LOGO: Sprite = Sprite::new(
+    Glyphs::Alphabet(b"~"),
+    Size::new(72, 16),
+    &[
+        0x00, 0xf0, 0xf0, 0x30, 0xf0, 0xe0, 0x30, 0xf0, 0xe0, 0x00, 0xf0, 0xf0, 0x00, 0xf0, 0xf0,
+        0x30, 0xf0, 0xe0, 0x00, 0xe0, 0xf0, 0xb0, 0xf0, 0xe0, 0x00, 0xe0, 0xf0, 0xb0, 0xb0, 0x30,
+        0x00, 0xf0, 0xf0, 0x00, 0xf0, 0xf0, 0x00, 0xf0, 0xf0, 0x00, 0xe0, 0xf0, 0xb0, 0xf0, 0xe0,
+        0x00, 0xe0, 0xf0, 0xb0, 0xf0, 0xe0, 0x00, 0xf0, 0xf0, 0x30, 0xf0, 0xe0, 0x00, 0xe0, 0xf0,
+        0xb0, 0xf0, 0xe0, 0x00, 0xf0, 0xf0, 0xb0, 0xf0, 0xe0, 0x00, 0x00, 0x00, 0x00, 0x0f, 0x0f,
+        0x00, 0x0f, 0x0f, 0x00, 0x0f, 0x0f, 0x00, 0x0f, 0x0f, 0x00, 0x0f, 0x0f, 0x00, 0x0f, 0x0f,
+        0x00, 0x07, 0x0f, 0x0d, 0x0d, 0x0d, 0x00, 0x0c, 0x0d, 0x0d, 0x0f, 0x07, 0x00, 0x00, 0x07,
+        0x0f, 0x07, 0x07, 0x0f, 0x07, 0x00, 0x00, 0x07, 0x0f, 0x0d, 0x0d, 0x0d, 0x00, 0x07, 0x0f,
+        0x0d, 0x0d, 0x0d, 0x00, 0x3f, 0x3f, 0x0c, 0x0f, 0x07, 0x00, 0x07, 0x0f, 0x0d, 0x0d, 0x0d,
+        0x00, 0x0f, 0x0f, 0x01, 0x0f, 0x0e, 0x00, 0x00, 0x00,
+    ],
+);
+
+pub const POPUP: Sprite = Sprite::new(
+    Glyphs::Alphabet(b"WLDC"),
+    Size::new(74, 32),
+    &[
+        0xff, 0x0f, 0x7f, 0xf1, 0x81, 0x01, 0x0f, 0x7f, 0xf1, 0x81, 0x01, 0x0f, 0x7f, 0xf1, 0x81,
+        0x01, 0x0f, 0x7f, 0xf1, 0x81, 0x01, 0x0f, 0x7f, 0xf1, 0x81, 0x01, 0x0f, 0x7f, 0xf1, 0x81,
+        0x01, 0x0f, 0x7f, 0xf1, 0x81, 0x01, 0x0f, 0x7f, 0xf1, 0x81, 0x01, 0x0f, 0x7f, 0xf1, 0x81,
+        0x01, 0x0f, 0x7f, 0xf1, 0x81, 0x01, 0x0f, 0x7f, 0xf1, 0x81, 0x01, 0x0f, 0x7f, 0xf1, 0x81,
+        0x01, 0x0f, 0x7f, 0xf1, 0x81, 0x01, 0x0f, 0x7f, 0xf1, 0x81, 0x01, 0x0f, 0x7f, 0xff, 0xff,
+        0x02, 0x02, 0x03, 0x03, 0x02, 0x02, 0x02, 0x03, 0x03, 0x02, 0x02, 0x02, 0x03, 0x83, 0x82,
+        0x02, 0x82, 0x83, 0x03, 0x02, 0x82, 0x82, 0x83, 0x03, 0x02, 0x82, 0x82, 0x03, 0x83, 0x82,
+        0x02, 0x02, 0x03, 0x03, 0x02, 0x02, 0x82, 0x83, 0x03, 0x82, 0x82, 0x02, 0x83, 0x83, 0x02,
+        0x02, 0x82, 0x83, 0x83, 0x02, 0x02, 0x82, 0x83, 0x83, 0x82, 0x02, 0x02, 0x83, 0x83, 0x02,
+        0x02, 0x02, 0x03, 0x03, 0x02, 0x02, 0x02, 0x03, 0x03, 0x02, 0x02, 0x02, 0xff, 0xff, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x9f, 0xbf, 0xb0,
+        0xff, 0xff, 0x00, 0x3f, 0x7f, 0x61, 0x7f, 0x3f, 0x00, 0x3f, 0x7f, 0x60, 0x7f, 0x3f, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x07, 0x3f, 0x78, 0x3f, 0x3f, 0x78, 0x3f, 0x07, 0x00, 0x3f,
+        0x7f, 0x61, 0x7f, 0x3f, 0x00, 0x7f, 0x7f, 0x01, 0x7f, 0x7f, 0x00, 0x6f, 0x6f, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0x80, 0x80,
+        0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x81, 0x81, 0x81, 0x81,
+        0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80,
+        0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80,
+        0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80,
+        0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0xff, 0xff, 0x0f, 0x7f, 0xf1,
+        0x81, 0x01, 0x0f, 0x7f, 0xf1, 0x81, 0x01, 0x0f, 0x7f, 0xf1, 0x81, 0x01, 0x0f, 0x7f, 0xf1,
+        0x81, 0x01, 0x0f, 0x7f, 0xf1, 0x81, 0x01, 0x0f, 0x7f, 0xf1, 0x81, 0x01, 0x0f, 0x7f, 0xf1,
+        0x81, 0x01, 0x0f, 0x7f, 0xf1, 0x81, 0x01, 0x0f, 0x7f, 0xf1, 0x81, 0x01, 0x0f, 0x7f, 0xf1,
+        0x81, 0x01, 0x0f, 0x7f, 0xf1, 0x81, 0x01, 0x0f, 0x7f, 0xf1, 0x81, 0x01, 0x0f, 0x7f, 0xf1,
+        0x81, 0x01, 0x0f, 0x7f, 0xf1, 0x81, 0x01, 0x0f, 0x7f, 0xff, 0xff, 0x02, 0x02, 0x03, 0x03,
+        0x02, 0x02, 0x02, 0x03, 0x83, 0x82, 0x82, 0x82, 0x03, 0x83, 0x82, 0x82, 0x82, 0x03, 0x03,
+        0x82, 0x82, 0x82, 0x83, 0x03, 0x82, 0x82, 0x02, 0x03, 0x03, 0x82, 0x82, 0x82, 0x03, 0x03,
+        0x02, 0x02, 0x02, 0x03, 0x03, 0x02, 0x82, 0x82, 0x83, 0x03, 0x02, 0x82, 0x82, 0x03, 0x83,
+        0x82, 0x02, 0x02, 0x83, 0x83, 0x82, 0x02, 0x02, 0x83, 0x83, 0x82, 0x82, 0x02, 0x03, 0x83,
+        0x82, 0x02, 0x02, 0x03, 0x03, 0x02, 0x02, 0x02, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x9f, 0xbf, 0xb1, 0xff, 0xff, 0x00, 0x79, 0x7d, 0x6d, 0x7f, 0x7f, 0x00, 0x7f,
+        0x7f, 0x01, 0x7f, 0x7f, 0x01, 0x7f, 0x7f, 0x00, 0x3f, 0x7f, 0x6d, 0x6f, 0x6f, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x3f, 0x7f, 0x61, 0x7f, 0x3f, 0x00, 0x07, 0x3f, 0x78, 0x3f, 0x07,
+        0x00, 0x3f, 0x7f, 0x6d, 0x6f, 0x6f, 0x00, 0x7f, 0x7f, 0x0d, 0x7f, 0x77, 0x00, 0x6f, 0x6f,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80,
+        0x80, 0x81, 0x81, 0x81, 0x81, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80,
+        0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80,
+        0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80,
+        0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80,
+        0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0xff, 0xff, 0x0f, 0x7f, 0xf1, 0x81, 0x01, 0x0f, 0x7f,
+        0xf1, 0x81, 0x01, 0x0f, 0x7f, 0xf1, 0x81, 0x01, 0x0f, 0x7f, 0xf1, 0x81, 0x01, 0x0f, 0x7f,
+        0xf1, 0x81, 0x01, 0x0f, 0x7f, 0xf1, 0x81, 0x01, 0x0f, 0x7f, 0xf1, 0x81, 0x01, 0x0f, 0x7f,
+        0xf1, 0x81, 0x01, 0x0f, 0x7f, 0xf1, 0x81, 0x01, 0x0f, 0x7f, 0xf1, 0x81, 0x01, 0x0f, 0x7f,
+        0xf1, 0x81, 0x01, 0x0f, 0x7f, 0xf1, 0x81, 0x01, 0x0f, 0x7f, 0xf1, 0x81, 0x01, 0x0f, 0x7f,
+        0xf1, 0x81, 0x01, 0x0f, 0x7f, 0xff, 0xff, 0x02, 0x02, 0x03, 0x03, 0x02, 0x02, 0x02, 0x03,
+        0x03, 0x02, 0x02, 0x02, 0x03, 0x03, 0x02, 0x02, 0x02, 0x03, 0x83, 0x82, 0x82, 0x82, 0x03,
+        0x03, 0x02, 0x82, 0x82, 0x83, 0x03, 0x02, 0x02, 0x02, 0x03, 0x83, 0x82, 0x82, 0x82, 0x03,
+        0x03, 0x82, 0x82, 0x82, 0x83, 0x03, 0x02, 0x02, 0x02, 0x03, 0x83, 0x82, 0x02, 0x82, 0x83,
+        0x03, 0x02, 0x02, 0x02, 0x03, 0x03, 0x02, 0x02, 0x02, 0x03, 0x03, 0x02, 0x02, 0x02, 0x03,
+        0x03, 0x02, 0x02, 0x02, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x7f, 0x7f, 0x01, 0x7f, 0x7f, 0x00,
+        0x3f, 0x7f, 0x61, 0x7f, 0x3f, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0x61, 0x7f, 0x3f, 0x00,
+        0x79, 0x7d, 0x6d, 0x7f, 0x7f, 0x00, 0x3c, 0x7e, 0x66, 0x7f, 0x7f, 0x00, 0x6f, 0x6f, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0xff, 0xff, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80,
+        0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80,
+        0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x81, 0x81, 0x80, 0x80, 0x80, 0x80, 0x80,
+        0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80,
+        0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80,
+        0x80, 0x80, 0xff, 0xff, 0x0f, 0x7f, 0xf1, 0x81, 0x01, 0x0f, 0x7f, 0xf1, 0x81, 0x01, 0x0f,
+        0x7f, 0xf1, 0x81, 0x01, 0x0f, 0x7f, 0xf1, 0x81, 0x01, 0x0f, 0x7f, 0xf1, 0x81, 0x01, 0x0f,
+        0x7f, 0xf1, 0x81, 0x01, 0x0f, 0x7f, 0xf1, 0x81, 0x01, 0x0f, 0x7f, 0xf1, 0x81, 0x01, 0x0f,
+        0x7f, 0xf1, 0x81, 0x01, 0x0f, 0x7f, 0xf1, 0x81, 0x01, 0x0f, 0x7f, 0xf1, 0x81, 0x01, 0x0f,
+        0x7f, 0xf1, 0x81, 0x01, 0x0f, 0x7f, 0xf1, 0x81, 0x01, 0x0f, 0x7f, 0xf1, 0x81, 0x01, 0x0f,
+        0x7f, 0xff, 0xff, 0x02, 0x02, 0x03, 0x03, 0x02, 0x02, 0x02, 0x03, 0x03, 0x02, 0x02, 0x02,
+        0x03, 0x83, 0x82, 0x82, 0x82, 0x03, 0x83, 0x82, 0x82, 0x82, 0x03, 0x03, 0x82, 0x82, 0x02,
+        0x03, 0x83, 0x82, 0x02, 0x82, 0x83, 0x03, 0x02, 0x02, 0x02, 0x83, 0x83, 0x82, 0x82, 0x02,
+        0x03, 0x83, 0x82, 0x82, 0x82, 0x03, 0x03, 0x02, 0x82, 0x82, 0x03, 0x03, 0x02, 0x02, 0x82,
+        0x83, 0x83, 0x02, 0x02, 0x02, 0x03, 0x03, 0x02, 0x02, 0x02, 0x03, 0x03, 0x02, 0x02, 0x02,
+        0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3f,
+        0x7f, 0x61, 0x61, 0x61, 0x00, 0x79, 0x7d, 0x6d, 0x7f, 0x7f, 0x00, 0x3f, 0x7f, 0x60, 0x00,
+        0x7f, 0x7f, 0x00, 0x7f, 0x7f, 0x66, 0x7e, 0x3c, 0x00, 0x7f, 0x7f, 0x0d, 0x7f, 0x77, 0x00,
+        0x79, 0x7d, 0x6d, 0x7f, 0x7f, 0x00, 0x06, 0x3f, 0x7f, 0x66, 0x60, 0x00, 0x3f, 0x7f, 0x6d,
+        0x6f, 0x6f, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff,
+        0xff, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80,
+        0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80,
+        0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80,
+        0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80,
+        0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0xff,
+    ],
+);
+
+pub const GAME_TILES: TileSet = TileSet::new(
+    8,
+    &[
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0c, 0x0c, 0x00, 0x00,
+        0x00, 0x00, 0x02, 0x2a, 0x2a, 0x0e, 0x0e, 0x00, 0x00, 0x0c, 0x3f, 0x3f, 0x7f, 0x7f, 0x3f,
+        0x3f, 0x0c, 0x00, 0x3e, 0x3e, 0x22, 0x3e, 0x3e, 0x00, 0x00, 0x00, 0x02, 0x3e, 0x3e, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x3a, 0x3a, 0x2a, 0x2e, 0x2e, 0x00, 0x00, 0x00, 0x2a, 0x2a, 0x2a,
+        0x3e, 0x3e, 0x00, 0x00, 0x00, 0x1e, 0x1e, 0x10, 0x3e, 0x3e, 0x00, 0x00, 0x00, 0x2e, 0x2e,
+        0x2a, 0x3a, 0x3a, 0x00, 0x00, 0x00, 0x3e, 0x3e, 0x2a, 0x3a, 0x3a, 0x00, 0x00, 0x00, 0x02,
+        0x02, 0x02, 0x3e, 0x3e, 0x00, 0x00, 0x00, 0x3e, 0x3e, 0x2a, 0x3e, 0x3e, 0x00, 0x00, 0xff,
+        0x81, 0x81, 0x81, 0x81, 0x81, 0x81, 0xff, 0xff, 0xff, 0xff, 0xe7, 0xe7, 0xff, 0xff, 0xff,
+        0xff, 0xfb, 0xab, 0xab, 0xe3, 0xe3, 0xff, 0xff, 0xc7, 0x03, 0x01, 0x01, 0x01, 0x01, 0x03,
+        0xc7, 0xff, 0x83, 0x83, 0xbb, 0x83, 0x83, 0xff, 0xff, 0xff, 0xfb, 0x83, 0x83, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0x8b, 0x8b, 0xab, 0xa3, 0xa3, 0xff, 0xff, 0xff, 0xab, 0xab, 0xab, 0x83,
+        0x83, 0xff, 0xff, 0xff, 0xc3, 0xc3, 0xdf, 0x83, 0x83, 0xff, 0xff, 0xff, 0xa3, 0xa3, 0xab,
+        0x8b, 0x8b, 0xff, 0xff, 0xff, 0x83, 0x83, 0xab, 0x8b, 0x8b, 0xff, 0xff, 0xff, 0xfb, 0xfb,
+        0xfb, 0x83, 0x83, 0xff, 0xff, 0xff, 0x83, 0x83, 0xab, 0x83, 0x83, 0xff, 0xff, 0x03, 0x03,
+        0x03, 0x03, 0x03, 0x03, 0x03, 0x03,
+    ],
+);
+
+pub const GAME_TILES_6X6: TileSet = TileSet::new(
+    6,
+    &[
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0e, 0x1f, 0x1f, 0x1f, 0x0e, 0x00, 0x00, 0x17, 0x1f,
+        0x12, 0x02, 0x00, 0x15, 0x0e, 0x1f, 0x0e, 0x15, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x12, 0x1f, 0x10, 0x00, 0x00, 0x00, 0x19, 0x15, 0x12, 0x00, 0x00, 0x00, 0x11, 0x15,
+        0x0a, 0x00, 0x00, 0x00, 0x07, 0x04, 0x1f, 0x00, 0x00, 0x00, 0x17, 0x15, 0x09, 0x00, 0x00,
+        0x00, 0x0e, 0x15, 0x09, 0x00, 0x00, 0x00, 0x01, 0x1d, 0x03, 0x00, 0x00, 0x00, 0x0a, 0x15,
+        0x0a, 0x00, 0x00, 0x3f, 0x3f, 0x3f, 0x3f, 0x3f, 0x3f, 0x31, 0x20, 0x20, 0x20, 0x31, 0x3f,
+        0x3f, 0x28, 0x20, 0x2d, 0x3d, 0x3f, 0x2a, 0x31, 0x20, 0x31, 0x2a, 0x3f, 0x3f, 0x3f, 0x3f,
+        0x3f, 0x3f, 0x3f, 0x3f, 0x2d, 0x20, 0x2f, 0x3f, 0x3f, 0x3f, 0x26, 0x2a, 0x2d, 0x3f, 0x3f,
+        0x3f, 0x2e, 0x2a, 0x35, 0x3f, 0x3f, 0x3f, 0x38, 0x3b, 0x20, 0x3f, 0x3f, 0x3f, 0x28, 0x2a,
+        0x36, 0x3f, 0x3f, 0x3f, 0x31, 0x2a, 0x36, 0x3f, 0x3f, 0x3f, 0x3e, 0x22, 0x3c, 0x3f, 0x3f,
+        0x3f, 0x35, 0x2a, 0x35, 0x3f, 0x3f,
+    ],
+);
+
+pub const GAME_TILES_4X4: TileSet = TileSet::new(
+    4,
+    &[
+        0x00, 0x00, 0x00, 0x00, 0x07, 0x07, 0x07, 0x00, 0x07, 0x03, 0x02, 0x00, 0x02, 0x07, 0x02,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x01, 0x00, 0x04, 0x00, 0x01, 0x02,
+        0x04, 0x00, 0x05, 0x00, 0x05, 0x00, 0x05, 0x02, 0x05, 0x00, 0x07, 0x00, 0x07, 0x00, 0x07,
+        0x02, 0x07, 0x00, 0x07, 0x05, 0x07, 0x00, 0x0f, 0x0f, 0x0f, 0x0f, 0x08, 0x08, 0x08, 0x0f,
+        0x08, 0x0c, 0x0d, 0x0f, 0x0d, 0x08, 0x0d, 0x0f, 0x0f, 0x0f, 0x0f, 0x0f, 0x0f, 0x0d, 0x0f,
+        0x0f, 0x0e, 0x0f, 0x0b, 0x0f, 0x0e, 0x0d, 0x0b, 0x0f, 0x0a, 0x0f, 0x0a, 0x0f, 0x0a, 0x0d,
+        0x0a, 0x0f, 0x08, 0x0f, 0x08, 0x0f, 0x08, 0x0d, 0x08, 0x0f, 0x08, 0x0a, 0x08, 0x0f,
+    ],
+);
+
+/// 6x8 font for ASCII from space to `~`, one byte per pixel column.
+pub const FONT: &[u8] = &[
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x5f, 0x00, 0x00, 0x00, 0x00, 0x07, 0x00, 0x07,
+    0x00, 0x00, 0x14, 0x7f, 0x14, 0x7f, 0x14, 0x00, 0x24, 0x2a, 0x7f, 0x2a, 0x12, 0x00, 0x23, 0x13,
+    0x08, 0x64, 0x62, 0x00, 0x36, 0x49, 0x55, 0x22, 0x50, 0x00, 0x00, 0x04, 0x03, 0x00, 0x00, 0x00,
+    0x00, 0x1c, 0x22, 0x41, 0x00, 0x00, 0x00, 0x41, 0x22, 0x1c, 0x00, 0x00, 0x14, 0x08, 0x3e, 0x08,
+    0x14, 0x00, 0x08, 0x08, 0x3e, 0x08, 0x08, 0x00, 0x00, 0x50, 0x30, 0x00, 0x00, 0x00, 0x08, 0x08,
+    0x08, 0x08, 0x08, 0x00, 0x00, 0x60, 0x60, 0x00, 0x00, 0x00, 0x20, 0x10, 0x08, 0x04, 0x02, 0x00,
+    0x3e, 0x51, 0x49, 0x45, 0x3e, 0x00, 0x00, 0x42, 0x7f, 0x40, 0x00, 0x00, 0x42, 0x61, 0x51, 0x49,
+    0x46, 0x00, 0x21, 0x41, 0x45, 0x4b, 0x31, 0x00, 0x18, 0x14, 0x12, 0x7f, 0x10, 0x00, 0x27, 0x45,
+    0x45, 0x45, 0x39, 0x00, 0x3c, 0x4a, 0x49, 0x49, 0x30, 0x00, 0x01, 0x71, 0x09, 0x05, 0x03, 0x00,
+    0x36, 0x49, 0x49, 0x49, 0x36, 0x00, 0x06, 0x49, 0x49, 0x29, 0x1e, 0x00, 0x00, 0x36, 0x36, 0x00,
+    0x00, 0x00, 0x00, 0x56, 0x36, 0x00, 0x00, 0x00, 0x08, 0x14, 0x22, 0x41, 0x00, 0x00, 0x14, 0x14,
+    0x14, 0x14, 0x14, 0x00, 0x00, 0x41, 0x22, 0x14, 0x08, 0x00, 0x02, 0x01, 0x51, 0x09, 0x06, 0x00,
+    0x32, 0x49, 0x79, 0x41, 0x3e, 0x00, 0x7e, 0x09, 0x09, 0x09, 0x7e, 0x00, 0x7f, 0x49, 0x49, 0x49,
+    0x36, 0x00, 0x3e, 0x41, 0x41, 0x41, 0x22, 0x00, 0x7f, 0x41, 0x41, 0x22, 0x1c, 0x00, 0x7f, 0x49,
+    0x49, 0x49, 0x41, 0x00, 0x7f, 0x09, 0x09, 0x09, 0x01, 0x00, 0x3e, 0x41, 0x49, 0x49, 0x7a, 0x00,
+    0x7f, 0x08, 0x08, 0x08, 0x7f, 0x00, 0x00, 0x41, 0x7f, 0x41, 0x00, 0x00, 0x20, 0x40, 0x41, 0x3f,
+    0x01, 0x00, 0x7f, 0x08, 0x14, 0x22, 0x41, 0x00, 0x7f, 0x40, 0x40, 0x40, 0x40, 0x00, 0x7f, 0x02,
+    0x0c, 0x02, 0x7f, 0x00, 0x7f, 0x04, 0x08, 0x10, 0x7f, 0x00, 0x3e, 0x41, 0x41, 0x41, 0x3e, 0x00,
+    0x7f, 0x09, 0x09, 0x09, 0x06, 0x00, 0x3e, 0x41, 0x51, 0x21, 0x5e, 0x00, 0x7f, 0x09, 0x19, 0x29,
+    0x46, 0x00, 0x46, 0x49, 0x49, 0x49, 0x31, 0x00, 0x01, 0x01, 0x7f, 0x01, 0x01, 0x00, 0x3f, 0x40,
+    0x40, 0x40, 0x3f, 0x00, 0x1f, 0x20, 0x40, 0x20, 0x1f, 0x00, 0x3f, 0x40, 0x38, 0x40, 0x3f, 0x00,
+    0x63, 0x14, 0x08, 0x14, 0x63, 0x00, 0x07, 0x08, 0x70, 0x08, 0x07, 0x00, 0x61, 0x51, 0x49, 0x45,
+    0x43, 0x00, 0x00, 0x7f, 0x41, 0x41, 0x00, 0x00, 0x02, 0x04, 0x08, 0x10, 0x20, 0x00, 0x00, 0x41,
+    0x41, 0x7f, 0x00, 0x00, 0x04, 0x02, 0x01, 0x02, 0x04, 0x00, 0x40, 0x40, 0x40, 0x40, 0x40, 0x00,
+    0x00, 0x01, 0x02, 0x04, 0x00, 0x00, 0x20, 0x54, 0x54, 0x54, 0x78, 0x00, 0x7f, 0x48, 0x44, 0x44,
+    0x38, 0x00, 0x38, 0x44, 0x44, 0x44, 0x20, 0x00, 0x38, 0x44, 0x44, 0x48, 0x7f, 0x00, 0x38, 0x54,
+    0x54, 0x54, 0x18, 0x00, 0x08, 0x7e, 0x09, 0x01, 0x02, 0x00, 0x0c, 0x52, 0x52, 0x52, 0x3e, 0x00,
+    0x7f, 0x08, 0x04, 0x04, 0x78, 0x00, 0x00, 0x44, 0x7d, 0x40, 0x00, 0x00, 0x20, 0x40, 0x44, 0x3d,
+    0x00, 0x00, 0x7f, 0x10, 0x28, 0x44, 0x00, 0x00, 0x00, 0x41, 0x7f, 0x40, 0x00, 0x00, 0x7c, 0x04,
+    0x18, 0x04, 0x78, 0x00, 0x7c, 0x08, 0x04, 0x04, 0x78, 0x00, 0x38, 0x44, 0x44, 0x44, 0x38, 0x00,
+    0x7c, 0x14, 0x14, 0x14, 0x08, 0x00, 0x08, 0x14, 0x14, 0x18, 0x7c, 0x00, 0x7c, 0x08, 0x04, 0x04,
+    0x08, 0x00, 0x48, 0x54, 0x54, 0x54, 0x20, 0x00, 0x04, 0x3f, 0x44, 0x40, 0x20, 0x00, 0x3c, 0x40,
+    0x40, 0x20, 0x7c, 0x00, 0x1c, 0x20, 0x40, 0x20, 0x1c, 0x00, 0x3c, 0x40, 0x30, 0x40, 0x3c, 0x00,
+    0x44, 0x28, 0x10, 0x28, 0x44, 0x00, 0x0c, 0x50, 0x50, 0x50, 0x3c, 0x00, 0x44, 0x64, 0x54, 0x4c,
+    0x44, 0x00, 0x00, 0x08, 0x36, 0x41, 0x00, 0x00, 0x00, 0x00, 0x7f, 0x00, 0x00, 0x00, 0x00, 0x41,
+    0x36, 0x08, 0x00, 0x00, 0x08, 0x04, 0x08, 0x10, 0x08, 0x00,
+];
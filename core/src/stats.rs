@@ -0,0 +1,142 @@
+//! Lifetime statistics per difficulty, kept in flash next to the settings.
+
+use crate::game::GameStatus;
+use crate::settings::{checksum, Difficulty};
+
+#[derive(Copy, Clone, Default, PartialEq, Eq, Debug)]
+pub struct LevelStats {
+    pub played: u16,
+    pub won: u16,
+    pub lost: u16,
+    pub streak: u16,
+    pub best_streak: u16,
+    /// Seconds spent in finished games.
+    pub play_secs: u32,
+    /// Seconds spent in won games.
+    pub win_secs: u32,
+}
+
+impl LevelStats {
+    const SIZE: usize = 18;
+
+    pub fn average_win_secs(&self) -> Option<u32> {
+        match self.won {
+            0 => None,
+            won => Some(self.win_secs / won as u32),
+        }
+    }
+
+    fn encode(&self) -> [u8; Self::SIZE] {
+        let mut record = [0; Self::SIZE];
+        record[0..2].copy_from_slice(&self.played.to_le_bytes());
+        record[2..4].copy_from_slice(&self.won.to_le_bytes());
+        record[4..6].copy_from_slice(&self.lost.to_le_bytes());
+        record[6..8].copy_from_slice(&self.streak.to_le_bytes());
+        record[8..10].copy_from_slice(&self.best_streak.to_le_bytes());
+        record[10..14].copy_from_slice(&self.play_secs.to_le_bytes());
+        record[14..18].copy_from_slice(&self.win_secs.to_le_bytes());
+        record
+    }
+
+    fn decode(record: &[u8]) -> Self {
+        let u16_at = |at: usize| u16::from_le_bytes([record[at], record[at + 1]]);
+        let u32_at = |at: usize| {
+            u32::from_le_bytes([record[at], record[at + 1], record[at + 2], record[at + 3]])
+        };
+        Self {
+            played: u16_at(0),
+            won: u16_at(2),
+            lost: u16_at(4),
+            streak: u16_at(6),
+            best_streak: u16_at(8),
+            play_secs: u32_at(10),
+            win_secs: u32_at(14),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Default, PartialEq, Eq, Debug)]
+pub struct Stats {
+    levels: [LevelStats; 3],
+}
+
+impl Stats {
+    pub const RECORD_SIZE: usize = 64;
+
+    const MAGIC: u8 = 0x53;
+    const VERSION: u8 = 2;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn level(&self, difficulty: Difficulty) -> &LevelStats {
+        &self.levels[difficulty as usize]
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Updates the counters for a game at `difficulty` going from `from` to `to`,
+    /// after `secs` seconds of play.
+    pub fn record(&mut self, difficulty: Difficulty, from: GameStatus, to: GameStatus, secs: u16) {
+        if from == to {
+            return;
+        }
+        let level = &mut self.levels[difficulty as usize];
+        let ended = matches!(to, GameStatus::Win | GameStatus::GameOver);
+        // The first click can also be the last one
+        if to == GameStatus::Playing || (ended && from != GameStatus::Playing) {
+            level.played = level.played.saturating_add(1);
+        }
+        if ended {
+            level.play_secs = level.play_secs.saturating_add(secs as u32);
+        }
+        match to {
+            GameStatus::Win => {
+                level.won = level.won.saturating_add(1);
+                level.win_secs = level.win_secs.saturating_add(secs as u32);
+                level.streak = level.streak.saturating_add(1);
+                level.best_streak = level.best_streak.max(level.streak);
+            }
+            GameStatus::GameOver => {
+                level.lost = level.lost.saturating_add(1);
+                level.streak = 0;
+            }
+            _ => {}
+        }
+    }
+
+    pub fn encode(&self) -> [u8; Self::RECORD_SIZE] {
+        let mut record = [0; Self::RECORD_SIZE];
+        record[0] = Self::MAGIC;
+        record[1] = Self::VERSION;
+        for (chunk, level) in record[2..]
+            .chunks_exact_mut(LevelStats::SIZE)
+            .zip(&self.levels)
+        {
+            chunk.copy_from_slice(&level.encode());
+        }
+        record[Self::RECORD_SIZE - 1] = checksum(&record[..Self::RECORD_SIZE - 1]);
+        record
+    }
+
+    pub fn decode(record: &[u8; Self::RECORD_SIZE]) -> Option<Self> {
+        if record[0] != Self::MAGIC
+            || record[1] != Self::VERSION
+            || record[Self::RECORD_SIZE - 1] != checksum(&record[..Self::RECORD_SIZE - 1])
+        {
+            return None;
+        }
+        let mut stats = Self::new();
+        for (level, chunk) in stats
+            .levels
+            .iter_mut()
+            .zip(record[2..].chunks_exact(LevelStats::SIZE))
+        {
+            *level = LevelStats::decode(chunk);
+        }
+        Some(stats)
+    }
+}
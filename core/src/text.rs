@@ -0,0 +1,127 @@
+use klaptik::*;
+
+use crate::sprites::FONT;
+
+/// Single line of ASCII text rendered with the `FONT` bitmap.
+///
+/// Edits go to a line buffer; only the span of characters that differ from
+/// what is on screen is drawn when rendering.
+pub struct Text<const LEN: usize> {
+    origin: Point,
+    line: [u8; LEN],
+    shown: [u8; LEN],
+}
+
+impl<const LEN: usize> Text<LEN> {
+    pub fn new(origin: Point) -> Self {
+        Self {
+            origin,
+            line: [b' '; LEN],
+            // Never printed, so the first render draws the whole line
+            shown: [0; LEN],
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.line = [b' '; LEN];
+    }
+
+    pub fn set_text(&mut self, text: impl AsRef<[u8]>) {
+        self.clear();
+        self.print(0, text);
+    }
+
+    pub fn set_number(&mut self, value: u32) {
+        self.clear();
+        self.print_number(LEN, value);
+    }
+
+    /// Prints text starting at `col`, clipped to the line length.
+    pub fn print(&mut self, col: usize, text: impl AsRef<[u8]>) {
+        print(&mut self.line, col, text.as_ref());
+    }
+
+    /// Prints text right-aligned so that it ends just before column `end`.
+    pub fn print_right(&mut self, end: usize, text: impl AsRef<[u8]>) {
+        print_right(&mut self.line, end, text.as_ref());
+    }
+
+    /// Prints decimal number right-aligned so that it ends just before column `end`.
+    pub fn print_number(&mut self, end: usize, value: u32) {
+        let mut digits = [0; 10];
+        print_right(&mut self.line, end, decimal(value, &mut digits));
+    }
+}
+
+// Line edits and rendering work on slices, so every line length and text type
+// shares one copy of the code
+
+fn print(line: &mut [u8], col: usize, text: &[u8]) {
+    for (cell, glyph) in line.iter_mut().skip(col).zip(text) {
+        *cell = match glyph {
+            0x20..=0x7e => *glyph,
+            _ => b'?',
+        };
+    }
+}
+
+fn print_right(line: &mut [u8], end: usize, text: &[u8]) {
+    let end = end.min(line.len());
+    let skip = text.len().saturating_sub(end);
+    print(line, end + skip - text.len(), &text[skip..]);
+}
+
+/// Decimal digits of `value`, kept clear of `core::fmt` which would not fit in flash.
+pub(crate) fn decimal(mut value: u32, digits: &mut [u8; 10]) -> &[u8] {
+    let mut start = digits.len();
+    loop {
+        start -= 1;
+        digits[start] = b'0' + (value % 10) as u8;
+        value /= 10;
+        if value == 0 {
+            break;
+        }
+    }
+    &digits[start..]
+}
+
+impl<const LEN: usize> Widget<&str> for Text<LEN> {
+    fn invalidate(&mut self) {
+        self.shown = [0; LEN];
+    }
+
+    fn update(&mut self, text: &str) {
+        self.set_text(text);
+    }
+
+    fn render<C: Canvas>(&mut self, canvas: &mut C) {
+        render(self.origin, &self.line, &mut self.shown, canvas);
+    }
+}
+
+/// Draws the span of `line` that differs from `shown` and marks it as shown.
+fn render<C: Canvas>(origin: Point, line: &[u8], shown: &mut [u8], canvas: &mut C) {
+    const GLYPH_WIDTH: usize = 6;
+
+    let changed = |idx: &usize| shown[*idx] != line[*idx];
+    let (Some(first), Some(last)) = (
+        (0..line.len()).find(changed),
+        (0..line.len()).rev().find(changed),
+    ) else {
+        return;
+    };
+    let mut strip = [0; 128];
+    let glyphs = &line[first..=last];
+    for (glyph, columns) in glyphs.iter().zip(strip.chunks_mut(GLYPH_WIDTH)) {
+        let start = (*glyph - b' ') as usize * GLYPH_WIDTH;
+        columns.copy_from_slice(&FONT[start..start + GLYPH_WIDTH]);
+    }
+    shown[first..=last].copy_from_slice(glyphs);
+
+    let width = glyphs.len() * GLYPH_WIDTH;
+    let origin = Point::new(origin.x + (first * GLYPH_WIDTH) as i32, origin.y);
+    canvas.draw(
+        Rectangle::new(origin, Size::new(width as _, 8)),
+        &strip[..width],
+    );
+}
@@ -0,0 +1,190 @@
+use klaptik::*;
+
+use crate::board::*;
+use crate::game::*;
+use crate::sprites::*;
+
+/// Board tile bitmaps: one byte per pixel column, top row in the least significant bit.
+/// Glyphs follow the `GAME_TILES` alphabet, starting at `,`.
+pub struct TileSet {
+    cell: usize,
+    bitmap: &'static [u8],
+}
+
+impl TileSet {
+    pub const fn new(cell: usize, bitmap: &'static [u8]) -> Self {
+        Self { cell, bitmap }
+    }
+
+    pub fn cell(&self) -> usize {
+        self.cell
+    }
+
+    fn column(&self, glyph: Glyph, col: usize) -> u8 {
+        self.bitmap[(glyph - b',') as usize * self.cell + col]
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum TileSize {
+    Large,
+    Medium,
+    Small,
+}
+
+impl TileSize {
+    pub fn tiles(&self) -> &'static TileSet {
+        match self {
+            TileSize::Large => &GAME_TILES,
+            TileSize::Medium => &GAME_TILES_6X6,
+            TileSize::Small => &GAME_TILES_4X4,
+        }
+    }
+
+    /// Number of columns and rows of a `width` x `height` board that fit on screen.
+    pub fn window(&self, width: usize, height: usize) -> (usize, usize) {
+        let cell = self.tiles().cell();
+        (
+            width.min(BoardView::WIDTH / cell),
+            height.min(BoardView::HEIGHT / cell),
+        )
+    }
+}
+
+/// Visible part of the board, centered in the area below the HUD.
+pub struct BoardView {
+    tiles: &'static TileSet,
+    origin: Point,
+    cols: usize,
+    rows: usize,
+    glyphs: [Glyph; Self::CELLS],
+    dirty: [(usize, usize); Self::PAGES],
+}
+
+impl Default for BoardView {
+    fn default() -> Self {
+        Self {
+            tiles: &GAME_TILES,
+            origin: Point::new(0, Self::TOP as i32),
+            cols: 0,
+            rows: 0,
+            glyphs: [b','; Self::CELLS],
+            dirty: [(0, Self::WIDTH); Self::PAGES],
+        }
+    }
+}
+
+impl BoardView {
+    pub const WIDTH: usize = 128;
+    pub const HEIGHT: usize = 48;
+    const TOP: usize = 16;
+    const CELLS: usize = (Self::WIDTH / 4) * (Self::HEIGHT / 4);
+    const PAGES: usize = Self::HEIGHT / 8;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Marks the display columns and pages covered by a cell for redraw.
+    fn mark_dirty(&mut self, col: usize, row: usize) {
+        let cell = self.tiles.cell();
+        let left = self.origin.x as usize + col * cell;
+        let top = self.origin.y as usize + row * cell - Self::TOP;
+        for page in top / 8..=(top + cell - 1) / 8 {
+            let (start, end) = &mut self.dirty[page];
+            if *start >= *end {
+                *start = left;
+                *end = left + cell;
+            } else {
+                *start = (*start).min(left);
+                *end = (*end).max(left + cell);
+            }
+        }
+    }
+
+    fn glyph_at(board: &Board, pos: Point) -> Glyph {
+        if !board.contains(pos) {
+            return b',';
+        }
+        let glyph = Glyph::from(&board.tile_at(pos));
+        if pos == board.cursor() {
+            glyph + 13
+        } else {
+            glyph
+        }
+    }
+
+    /// Composes one display page of the board area.
+    fn render_page(&self, page: usize, strip: &mut [u8; Self::WIDTH]) {
+        let cell = self.tiles.cell();
+        let left = self.origin.x as usize;
+        let top = self.origin.y as usize;
+        for (x, byte) in strip.iter_mut().enumerate() {
+            *byte = 0;
+            if x < left || x >= left + self.cols * cell {
+                continue;
+            }
+            let (col, tile_x) = ((x - left) / cell, (x - left) % cell);
+            for bit in 0..8 {
+                let y = page * 8 + bit;
+                if y < top || y >= top + self.rows * cell {
+                    continue;
+                }
+                let (row, tile_y) = ((y - top) / cell, (y - top) % cell);
+                let glyph = self.glyphs[row * self.cols + col];
+                if self.tiles.column(glyph, tile_x) >> tile_y & 1 != 0 {
+                    *byte |= 1 << bit;
+                }
+            }
+        }
+    }
+}
+
+impl Widget<&Minesweeper> for BoardView {
+    fn invalidate(&mut self) {
+        self.dirty = [(0, Self::WIDTH); Self::PAGES];
+    }
+
+    fn update(&mut self, game: &Minesweeper) {
+        let board = game.board();
+        let viewport = board.viewport();
+        let tiles = game.config().tiles.tiles();
+        let (cols, rows) = (viewport.cols(), viewport.rows());
+        if !core::ptr::eq(tiles, self.tiles) || cols != self.cols || rows != self.rows {
+            self.tiles = tiles;
+            self.cols = cols;
+            self.rows = rows;
+            self.origin = Point::new(
+                ((Self::WIDTH - cols * tiles.cell()) / 2) as i32,
+                (Self::TOP + (Self::HEIGHT - rows * tiles.cell()) / 2) as i32,
+            );
+            self.invalidate();
+        }
+
+        for row in 0..rows {
+            for col in 0..cols {
+                let glyph = Self::glyph_at(board, viewport.board_pos(col, row));
+                let cell = &mut self.glyphs[row * cols + col];
+                if *cell != glyph {
+                    *cell = glyph;
+                    self.mark_dirty(col, row);
+                }
+            }
+        }
+    }
+
+    fn render<C: Canvas>(&mut self, canvas: &mut C) {
+        let mut strip = [0; Self::WIDTH];
+        for idx in 0..Self::PAGES {
+            let (start, end) = core::mem::take(&mut self.dirty[idx]);
+            if start >= end {
+                continue;
+            }
+            let page = Self::TOP / 8 + idx;
+            self.render_page(page, &mut strip);
+            let origin = Point::new(start as i32, page as i32 * 8);
+            let bounds = Rectangle::new(origin, Size::new((end - start) as _, 8));
+            canvas.draw(bounds, &strip[start..end]);
+        }
+    }
+}
@@ -0,0 +1,400 @@
+use klaptik::*;
+
+use crate::challenge::*;
+use crate::game::*;
+use crate::menu::*;
+use crate::settings::*;
+use crate::sprites::*;
+use crate::stats::*;
+use crate::text::*;
+
+pub struct UiState<'a> {
+    pub game: &'a Minesweeper,
+    pub menu: &'a Menu,
+    pub settings: &'a Settings,
+    pub stats: &'a Stats,
+}
+
+widget_group! {
+    GameUI<&UiState<'_>>,
+    {
+        bg: Background, Point::new(0, 0), Size::new(128, 64);
+        logo: GlyphIcon, LOGO, b'~', Point::new(0, 0);
+        hud: Hud;
+        screens: PauseOverlay;
+    },
+    |game_ui: &mut GameUI, state: &UiState| {
+        game_ui.hud.update(state);
+        game_ui.screens.update(state);
+    }
+}
+
+widget_mux! {
+    Screens<&UiState<'_>>,
+    ScreensNode::Menu,
+    {
+        game: GameScreen;
+        menu: MenuScreen;
+        scores: ScoresScreen;
+        code: CodeScreen;
+    },
+    |mux: &mut Screens, state: &UiState| {
+        match state.menu.screen() {
+            Screen::Game => {
+                mux.set_active(ScreensNode::Game);
+                mux.game.update(state.game);
+            }
+            Screen::Scores => {
+                mux.set_active(ScreensNode::Scores);
+                mux.scores.update(state.menu);
+            }
+            Screen::CodeEntry => {
+                mux.set_active(ScreensNode::Code);
+                mux.code.update(state.menu);
+            }
+            _ => {
+                mux.set_active(ScreensNode::Menu);
+                mux.menu.update(state);
+            }
+        }
+    }
+}
+
+/// Screens with the pause popups drawn over them, in menus as well as in game.
+pub struct PauseOverlay {
+    screens: Screens,
+    /// Clears what the popup covered, not every screen draws over all of it.
+    under: Background,
+    popup: GlyphIcon,
+    pause: Option<Pause>,
+}
+
+impl Default for PauseOverlay {
+    fn default() -> Self {
+        Self {
+            screens: Screens::new(),
+            under: Background::new(Self::ORIGIN, Size::new(74, 32)),
+            popup: GlyphIcon::new(POPUP, b'D', Self::ORIGIN),
+            pause: None,
+        }
+    }
+}
+
+impl PauseOverlay {
+    const ORIGIN: Point = Point::new(24, 24);
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Widget<&UiState<'_>> for PauseOverlay {
+    fn invalidate(&mut self) {
+        self.screens.invalidate();
+        self.under.invalidate();
+        self.popup.invalidate();
+    }
+
+    fn update(&mut self, state: &UiState<'_>) {
+        let pause = state.game.paused();
+        if pause != self.pause {
+            self.pause = pause;
+            self.invalidate();
+        }
+        if let Some(pause) = pause {
+            self.popup.update(match pause {
+                Pause::Disconnected => b'D',
+                Pause::Calibration => b'C',
+            });
+        }
+        self.screens.update(state);
+    }
+
+    fn render<C: Canvas>(&mut self, canvas: &mut C) {
+        if self.pause.is_none() {
+            self.under.render(canvas);
+            self.screens.render(canvas);
+            return;
+        }
+        // Anything redrawn below may have painted over the popup
+        self.screens.render(canvas);
+        self.popup.invalidate();
+        self.popup.render(canvas);
+    }
+}
+
+pub type MenuLine = Text<21>;
+
+pub struct MenuScreen {
+    rows: [MenuLine; Menu::ROWS],
+}
+
+impl Default for MenuScreen {
+    fn default() -> Self {
+        Self {
+            rows: core::array::from_fn(|row| MenuLine::new(Point::new(1, 16 + row as i32 * 8))),
+        }
+    }
+}
+
+impl MenuScreen {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Widget<&UiState<'_>> for MenuScreen {
+    fn invalidate(&mut self) {
+        self.rows.iter_mut().for_each(|row| row.invalidate());
+    }
+
+    fn update(&mut self, state: &UiState<'_>) {
+        let mut items = state.menu.visible_items();
+        for row in self.rows.iter_mut() {
+            row.clear();
+            if let Some((item, selected)) = items.next() {
+                if selected {
+                    row.print(0, ">");
+                }
+                row.print(2, item_label(item));
+                match item {
+                    MenuItem::Stat(kind) => {
+                        print_stat(row, kind, state.stats.level(state.menu.stats_level()))
+                    }
+                    MenuItem::BoardCode => {
+                        // Boards the remote dealt with another mine count have no code
+                        let dealt = state.game.status() != GameStatus::Bootstrap;
+                        let difficulty = Difficulty::from_config(&state.game.config());
+                        if let Some(difficulty) = difficulty.filter(|_| dealt) {
+                            let code = BoardCode::new(difficulty, state.game.board_seed());
+                            row.print_right(21, code.format());
+                        }
+                    }
+                    MenuItem::DeadZone => {
+                        row.print_right(21, "%");
+                        row.print_number(20, state.settings.dead_zone as u32);
+                    }
+                    MenuItem::Year => row.print_number(21, state.menu.date().year() as u32),
+                    MenuItem::Month => row.print_number(21, state.menu.date().month() as u32),
+                    MenuItem::Day => row.print_number(21, state.menu.date().day() as u32),
+                    _ => row.print_right(21, item_value(item, state)),
+                }
+            }
+        }
+    }
+
+    fn render<C: Canvas>(&mut self, canvas: &mut C) {
+        self.rows.iter_mut().for_each(|row| row.render(canvas));
+    }
+}
+
+widget_group! {
+    ScoresScreen<&Menu>,
+    {
+        title: MenuLine, Point::new(1, 24);
+        easy: MenuLine, Point::new(1, 40);
+        normal: MenuLine, Point::new(1, 48);
+        hard: MenuLine, Point::new(1, 56);
+    },
+    |screen: &mut ScoresScreen, menu: &Menu| {
+        screen.title.set_text("  Best times");
+        let mut rows = [&mut screen.easy, &mut screen.normal, &mut screen.hard];
+        for (row, difficulty) in rows.iter_mut().zip(Difficulty::ALL.iter()) {
+            row.set_text(difficulty_label(*difficulty));
+            match menu.best_time(*difficulty) {
+                Some(secs) => row.print_right(21, format_time(secs)),
+                None => row.print_right(21, "--:--"),
+            }
+        }
+    }
+}
+
+widget_group! {
+    CodeScreen<&Menu>,
+    {
+        title: MenuLine, Point::new(1, 24);
+        code: MenuLine, Point::new(1, 40);
+        caret: MenuLine, Point::new(1, 48);
+        hint: MenuLine, Point::new(1, 56);
+    },
+    |screen: &mut CodeScreen, menu: &Menu| {
+        screen.title.set_text("  Enter board code");
+        screen.code.clear();
+        for (idx, &symbol) in menu.code_symbols().iter().enumerate() {
+            let glyph = BoardCode::ALPHABET[symbol as usize];
+            screen.code.print(5 + idx + idx / 5, [glyph]);
+        }
+        screen.code.print(10, "-");
+        let cursor = menu.code_cursor();
+        screen.caret.clear();
+        screen.caret.print(5 + cursor + cursor / 5, "^");
+        screen.hint.set_text(if menu.code_error() {
+            "  Not a valid code"
+        } else {
+            "  Open to play"
+        });
+    }
+}
+
+pub type HudLine = Text<6>;
+pub type ScrollLine = Text<3>;
+
+widget_group! {
+    Hud<&UiState<'_>>,
+    {
+        scroll_up: ScrollLine, Point::new(74, 0);
+        scroll_down: ScrollLine, Point::new(74, 8);
+        mines: HudLine, Point::new(92, 0);
+        time: HudLine, Point::new(92, 8);
+    },
+    |hud: &mut Hud, state: &UiState| {
+        hud.scroll_up.clear();
+        hud.scroll_down.clear();
+        hud.mines.clear();
+        hud.time.clear();
+        if state.menu.screen() == Screen::Game {
+            let viewport = state.game.board().viewport();
+            let mark = |more, glyph| if more { glyph } else { " " };
+            hud.scroll_up.print(1, mark(viewport.more_above(), "^"));
+            hud.scroll_down.print(0, mark(viewport.more_left(), "<"));
+            hud.scroll_down.print(1, mark(viewport.more_below(), "v"));
+            hud.scroll_down.print(2, mark(viewport.more_right(), ">"));
+            hud.mines.print_number(6, state.game.mines_left() as u32);
+            hud.time.print_right(6, format_time(state.game.elapsed_secs()));
+        }
+    }
+}
+
+pub fn format_time(secs: u16) -> [u8; 5] {
+    let mins = (secs / 60).min(99);
+    let secs = secs % 60;
+    [
+        b'0' + (mins / 10) as u8,
+        b'0' + (mins % 10) as u8,
+        b':',
+        b'0' + (secs / 10) as u8,
+        b'0' + (secs % 10) as u8,
+    ]
+}
+
+fn print_stat(row: &mut MenuLine, kind: StatKind, stats: &LevelStats) {
+    let count = match kind {
+        StatKind::Played => stats.played,
+        StatKind::Won => stats.won,
+        StatKind::Lost => stats.lost,
+        StatKind::Streak => stats.streak,
+        StatKind::BestStreak => stats.best_streak,
+        _ => 0,
+    };
+    match kind {
+        StatKind::PlayTime if stats.play_secs < 100 * 60 => {
+            row.print_right(21, format_time(stats.play_secs as u16))
+        }
+        StatKind::PlayTime => {
+            // Hours and minutes once the clock format runs out
+            let mins = stats.play_secs / 60 % 60;
+            row.print_right(
+                21,
+                [b'h', b'0' + (mins / 10) as u8, b'0' + (mins % 10) as u8],
+            );
+            row.print_number(18, stats.play_secs / 3600);
+        }
+        StatKind::AverageWin => match stats.average_win_secs() {
+            Some(secs) => row.print_right(21, format_time(secs.min(u16::MAX as u32) as u16)),
+            None => row.print_right(21, "--:--"),
+        },
+        _ => row.print_number(21, count as u32),
+    }
+}
+
+fn difficulty_label(difficulty: Difficulty) -> &'static str {
+    match difficulty {
+        Difficulty::Easy => "Easy",
+        Difficulty::Normal => "Normal",
+        Difficulty::Hard => "Hard",
+    }
+}
+
+/// Labels in `StatKind` order.
+const STAT_LABELS: [&str; 7] = [
+    "Played",
+    "Won",
+    "Lost",
+    "Win streak",
+    "Best streak",
+    "Play time",
+    "Average win",
+];
+
+const CREDITS: [&str; 5] = [
+    "minesweeper.rs",
+    "by Vitaly Domnikov",
+    "MIT / Apache-2.0",
+    "github.com/",
+    "  dotcypress",
+];
+
+fn item_label(item: MenuItem) -> &'static str {
+    match item {
+        MenuItem::NewGame => "New game",
+        MenuItem::Continue => "Continue",
+        MenuItem::Difficulty => "Difficulty",
+        MenuItem::Settings => "Settings",
+        MenuItem::Scores => "Scores",
+        MenuItem::Stats => "Stats",
+        MenuItem::Challenge => "Challenge",
+        MenuItem::About => "About",
+        MenuItem::Level(difficulty) => difficulty_label(difficulty),
+        MenuItem::ControlMode => "Controls",
+        MenuItem::DeadZone => "Dead zone",
+        MenuItem::Diagonal => "Diagonal",
+        MenuItem::SwapButtons => "Swap buttons",
+        MenuItem::InvertX => "Invert X",
+        MenuItem::InvertY => "Invert Y",
+        MenuItem::LeftHanded => "Left handed",
+        MenuItem::ChordButton => "Chord with",
+        #[cfg(feature = "sound")]
+        MenuItem::Sound => "Sound",
+        MenuItem::Calibrate => "Calibrate",
+        MenuItem::StatsLevel => "Level",
+        MenuItem::Stat(kind) => STAT_LABELS[kind as usize],
+        MenuItem::ResetStats => "Reset all",
+        MenuItem::BoardCode => "Board",
+        MenuItem::Year => "Year",
+        MenuItem::Month => "Month",
+        MenuItem::Day => "Day",
+        MenuItem::PlayDaily => "Play daily",
+        MenuItem::EnterCode => "Enter code",
+        MenuItem::Credits(line) => CREDITS[(line as usize).min(CREDITS.len() - 1)],
+    }
+}
+
+fn item_value(item: MenuItem, state: &UiState) -> &'static str {
+    let settings = state.settings;
+    let switch = |on| if on { "on" } else { "off" };
+    let map = &settings.action_map;
+    match item {
+        MenuItem::Level(difficulty) if difficulty == settings.difficulty => "<",
+        MenuItem::ControlMode => match settings.control_mode {
+            ControlMode::Stick => "stick",
+            ControlMode::Tilt => "tilt",
+        },
+        MenuItem::Diagonal => switch(settings.diagonal),
+        MenuItem::SwapButtons => switch(map.swap_buttons),
+        MenuItem::InvertX => switch(map.invert_x),
+        MenuItem::InvertY => switch(map.invert_y),
+        MenuItem::LeftHanded => switch(map.left_handed),
+        MenuItem::ChordButton => match map.chord {
+            ChordButton::Both => "both",
+            ChordButton::Open => "open",
+            ChordButton::Flag => "flag",
+        },
+        #[cfg(feature = "sound")]
+        MenuItem::Sound => switch(settings.sound),
+        MenuItem::StatsLevel => difficulty_label(state.menu.stats_level()),
+        MenuItem::ResetStats if state.menu.confirming_reset() => "sure?",
+        MenuItem::BoardCode => "none",
+        MenuItem::PlayDaily => difficulty_label(settings.difficulty),
+        _ => "",
+    }
+}
@@ -0,0 +1,87 @@
+use klaptik::*;
+
+/// Window of the board visible on the display, scrolled to keep the cursor in view.
+#[derive(Copy, Clone)]
+pub struct Viewport {
+    origin: Point,
+    cols: usize,
+    rows: usize,
+    width: usize,
+    height: usize,
+}
+
+impl Viewport {
+    const MARGIN: i32 = 1;
+
+    pub fn new(cols: usize, rows: usize) -> Self {
+        Self {
+            origin: Point::new(0, 0),
+            cols,
+            rows,
+            width: 0,
+            height: 0,
+        }
+    }
+
+    pub fn origin(&self) -> Point {
+        self.origin
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn set_window(&mut self, cols: usize, rows: usize) {
+        self.cols = cols;
+        self.rows = rows;
+    }
+
+    /// Board position shown at the given viewport cell.
+    pub fn board_pos(&self, col: usize, row: usize) -> Point {
+        Point::new(self.origin.x + col as i32, self.origin.y + row as i32)
+    }
+
+    pub fn more_left(&self) -> bool {
+        self.origin.x > 0
+    }
+
+    pub fn more_right(&self) -> bool {
+        self.origin.x + (self.cols as i32) < self.width as i32
+    }
+
+    pub fn more_above(&self) -> bool {
+        self.origin.y > 0
+    }
+
+    pub fn more_below(&self) -> bool {
+        self.origin.y + (self.rows as i32) < self.height as i32
+    }
+
+    pub fn follow(&mut self, cursor: Point, width: usize, height: usize) {
+        self.width = width;
+        self.height = height;
+        self.origin = Point::new(
+            Self::scroll(self.origin.x, cursor.x, self.cols, width),
+            Self::scroll(self.origin.y, cursor.y, self.rows, height),
+        );
+    }
+
+    fn scroll(origin: i32, cursor: i32, visible: usize, total: usize) -> i32 {
+        let visible = visible as i32;
+        let max_origin = (total as i32 - visible).max(0);
+        let margin = Self::MARGIN.min((visible - 1) / 2);
+
+        let origin = if cursor < origin + margin {
+            cursor - margin
+        } else if cursor > origin + visible - 1 - margin {
+            cursor + margin + 1 - visible
+        } else {
+            origin
+        };
+        origin.clamp(0, max_origin)
+    }
+}
@@ -0,0 +1,217 @@
+//! Daily boards and board codes: dealing the same board from a date or code.
+
+use klaptik::Point;
+use minesweeper_core::board::*;
+use minesweeper_core::challenge::*;
+use minesweeper_core::game::*;
+use minesweeper_core::input::Action;
+use minesweeper_core::menu::*;
+use minesweeper_core::settings::*;
+
+mod common;
+
+use common::move_to;
+
+/// Deals the board for `code` with the first click at `first`, returns the mines.
+fn deal(code: BoardCode, first: Point) -> Vec<bool> {
+    let mut game = Minesweeper::new(code.config());
+    game.seed_random(code.seed());
+    move_to(&mut game, first);
+    game.button_click(GameButton::A);
+    assert_eq!(game.board_seed(), code.seed());
+    game.board()
+        .tiles()
+        .iter()
+        .map(|tile| tile.content() == TileContent::Bomb)
+        .collect()
+}
+
+fn date(year: u16, month: u8, day: u8) -> Date {
+    Date::new(year, month, day).unwrap()
+}
+
+#[test]
+fn code_round_trips() {
+    for difficulty in Difficulty::ALL {
+        for seed in [1, 42, 0x1234_5678, 0x7fff_fffe] {
+            let code = BoardCode::new(difficulty, seed);
+            let text = code.format();
+            assert_eq!(text[5], b'-');
+            let text = core::str::from_utf8(&text).unwrap();
+            assert!(BoardCode::parse(text) == Some(code), "{}", text);
+            assert!(BoardCode::from_symbols(&code.symbols()) == Some(code));
+        }
+    }
+}
+
+#[test]
+fn parse_forgives_case_and_lookalikes() {
+    let code = BoardCode::new(Difficulty::Normal, 0x0111_0000);
+    let text = String::from_utf8(code.format().to_vec()).unwrap();
+    let typed = text
+        .to_lowercase()
+        .replace('1', "l")
+        .replace('0', "o")
+        .replace('-', " ");
+    assert!(BoardCode::parse(&typed) == Some(code), "{}", typed);
+    assert!(BoardCode::parse(&text[..10]).is_none(), "short code");
+    assert!(
+        BoardCode::parse(&format!("{}0", text)).is_none(),
+        "long code"
+    );
+    assert!(
+        BoardCode::parse("UUUUU-UUUUU").is_none(),
+        "not in the alphabet"
+    );
+}
+
+#[test]
+fn checksum_catches_typos() {
+    let code = BoardCode::new(Difficulty::Hard, 987_654_321);
+    let symbols = code.symbols();
+    for idx in 0..BoardCode::SYMBOLS {
+        for delta in 1..32 {
+            let mut typo = symbols;
+            typo[idx] = (typo[idx] + delta) % 32;
+            assert!(
+                BoardCode::from_symbols(&typo).is_none(),
+                "typo at {} accepted",
+                idx
+            );
+        }
+    }
+}
+
+/// Deals every first click of `code`, returns the seeded layout and where a
+/// mine under the first click moved to.
+fn relocation(code: BoardCode) -> (Vec<bool>, usize) {
+    let config = code.config();
+    let deals: Vec<Vec<bool>> = (0..config.height as i32)
+        .flat_map(|y| (0..config.width as i32).map(move |x| Point::new(x, y)))
+        .map(|first| deal(code, first))
+        .collect();
+    // Few clicks land on a mine, so most deals show the seeded layout
+    let seeded: Vec<bool> = (0..deals.len())
+        .map(|tile| deals.iter().filter(|mines| mines[tile]).count() * 2 > deals.len())
+        .collect();
+
+    let mut target = None;
+    for (offset, mines) in deals.iter().enumerate() {
+        if !seeded[offset] {
+            assert_eq!(mines, &seeded, "first click at tile {}", offset);
+            continue;
+        }
+        let moved: Vec<usize> = (0..mines.len())
+            .filter(|&tile| mines[tile] != seeded[tile])
+            .collect();
+        assert_eq!(moved.len(), 2, "first click at tile {}", offset);
+        assert!(moved.contains(&offset) && !mines[offset]);
+        let to = moved.into_iter().find(|&tile| tile != offset).unwrap();
+        assert_eq!(
+            *target.get_or_insert(to),
+            to,
+            "first click at tile {}",
+            offset
+        );
+    }
+    (seeded, target.expect("no click landed on a mine"))
+}
+
+#[test]
+fn only_the_first_click_mine_moves() {
+    let mut away_from_first_free = 0;
+    for seed in 2024..2032 {
+        let (seeded, target) = relocation(BoardCode::new(Difficulty::Normal, seed));
+        assert!(!seeded[target], "seed {}: moved onto a mine", seed);
+        if seeded.iter().position(|&mine| !mine) != Some(target) {
+            away_from_first_free += 1;
+        }
+    }
+    // Picked by the generator rather than the first free tile in reading order
+    assert!(away_from_first_free > 4);
+}
+
+#[test]
+fn daily_seed_follows_date() {
+    let today = date(2025, 3, 14);
+    assert_eq!(today.seed(), date(2025, 3, 14).seed());
+    assert_ne!(today.seed(), date(2025, 3, 15).seed());
+    assert_ne!(today.seed(), date(2026, 3, 14).seed());
+    assert_eq!(&today.format(), b"2025-03-14");
+    // 2025-03-14 was a Friday
+    assert_eq!(today.weekday(), 5);
+}
+
+#[test]
+fn dates_stay_valid_when_stepped() {
+    assert!(Date::new(2023, 2, 29).is_none());
+    assert!(Date::new(2024, 2, 29).is_some());
+    assert!(Date::new(1999, 1, 1).is_none());
+
+    let mut day = date(2024, 1, 31);
+    day.step_month(true);
+    assert_eq!(day, date(2024, 2, 29));
+    day.step_year(true);
+    assert_eq!(day, date(2025, 2, 28));
+    day.step_day(true);
+    assert_eq!(day, date(2025, 2, 1));
+    day.step_month(false);
+    day.step_month(false);
+    assert_eq!(
+        day,
+        date(2025, 12, 1),
+        "month wraps without touching the year"
+    );
+}
+
+#[test]
+fn menu_plays_daily_and_entered_codes() {
+    let mut menu = Menu::new();
+    let mut settings = Settings::default();
+    let title = Screen::Title.items();
+    let challenge = title
+        .iter()
+        .position(|&item| item == MenuItem::Challenge)
+        .unwrap();
+    for _ in 0..challenge {
+        menu.handle(Action::Move(Dir::Down), &mut settings);
+    }
+    assert!(menu.handle(Action::Open, &mut settings) == MenuEvent::Challenge);
+    assert!(menu.screen() == Screen::Challenge);
+
+    // Year, then one day back from the default date
+    menu.handle(Action::Move(Dir::Down), &mut settings);
+    menu.handle(Action::Move(Dir::Right), &mut settings);
+    menu.handle(Action::Move(Dir::Down), &mut settings);
+    menu.handle(Action::Move(Dir::Down), &mut settings);
+    menu.handle(Action::Move(Dir::Left), &mut settings);
+    assert_eq!(menu.date(), date(2026, 1, 31));
+
+    menu.handle(Action::Move(Dir::Down), &mut settings);
+    assert!(menu.handle(Action::Open, &mut settings) == MenuEvent::PlayDaily);
+
+    menu.handle(Action::Move(Dir::Down), &mut settings);
+    menu.handle(Action::Open, &mut settings);
+    assert!(menu.screen() == Screen::CodeEntry);
+
+    // All zeroes is no board this firmware deals
+    assert!(menu.handle(Action::Open, &mut settings) == MenuEvent::None);
+    assert!(menu.code_error());
+
+    let code = BoardCode::new(Difficulty::Easy, 31337);
+    for (idx, &target) in code.symbols().iter().enumerate() {
+        assert_eq!(menu.code_cursor(), idx);
+        while menu.code_symbols()[idx] != target {
+            menu.handle(Action::Move(Dir::Up), &mut settings);
+        }
+        menu.handle(Action::Move(Dir::Right), &mut settings);
+        if idx == BoardCode::SYMBOLS - 1 {
+            assert_eq!(menu.code_cursor(), idx, "cursor stays on the last symbol");
+        }
+    }
+    assert!(!menu.code_error());
+    assert!(menu.handle(Action::Open, &mut settings) == MenuEvent::PlayCode(code));
+
+    menu.handle(Action::Flag, &mut settings);
+    assert!(menu.screen() == Screen::Challenge);
+}
@@ -0,0 +1,12 @@
+//! Helpers shared by the integration tests.
+
+use klaptik::Point;
+use minesweeper_core::game::Minesweeper;
+use minesweeper_core::player::Buttons;
+
+/// Walks the cursor to `target` with the d-pad, like a player would.
+pub fn move_to(game: &mut Minesweeper, target: Point) {
+    for button in Buttons::walk(game.board().cursor(), target) {
+        game.button_click(button);
+    }
+}
@@ -0,0 +1,79 @@
+//! Debug console cheats, only built with the `debug-console` feature.
+#![cfg(feature = "debug-console")]
+
+use klaptik::Point;
+use minesweeper_core::board::*;
+use minesweeper_core::console::*;
+use minesweeper_core::game::*;
+use minesweeper_core::settings::Difficulty;
+
+fn run(game: &mut Minesweeper, input: &str) -> String {
+    let mut console = Console::new();
+    let mut out = String::new();
+    for byte in input.bytes() {
+        console.feed(byte, game, &mut out).unwrap();
+    }
+    out
+}
+
+fn started_game() -> Minesweeper {
+    let mut game = Minesweeper::new(Difficulty::Easy.config());
+    game.seed_random(7);
+    game.button_click(GameButton::A);
+    game
+}
+
+#[test]
+fn seed_makes_boards_reproducible() {
+    let mut boards = Vec::new();
+    for _ in 0..2 {
+        let mut game = Minesweeper::new(Difficulty::Easy.config());
+        assert_eq!(run(&mut game, "seed 99\nreveal\n"), "done\ndone\n");
+        game.button_click(GameButton::A);
+        boards.push(run(&mut game, "reveal\nboard\n"));
+    }
+    assert_eq!(boards[0], boards[1]);
+}
+
+#[test]
+fn mine_updates_hints() {
+    let mut game = started_game();
+    let pos = (0..16)
+        .flat_map(|x| (0..6).map(move |y| Point::new(x, y)))
+        .find(|&pos| game.board().tile_at(pos).status() == TileStatus::Closed)
+        .unwrap();
+    let bombs = game.config().bombs;
+
+    assert_eq!(run(&mut game, &format!("mine {} {}\n", pos.x, pos.y)), "done\n");
+    assert!(game.board().tile_at(pos).content() == TileContent::Bomb);
+    assert_eq!(game.config().bombs, bombs + 1);
+    let board = game.board();
+    for neighbor in board.neighbors(pos) {
+        if let TileContent::Hint(hint) = board.tile_at(neighbor).content() {
+            let around = board
+                .neighbors(neighbor)
+                .filter(|&pos| board.tile_at(pos).content() == TileContent::Bomb)
+                .count();
+            assert_eq!(hint as usize, around);
+        }
+    }
+}
+
+#[test]
+fn cursor_and_forced_endings() {
+    let mut game = started_game();
+    assert_eq!(run(&mut game, "cursor 3 4\n"), "done\n");
+    assert_eq!(game.board().cursor(), Point::new(3, 4));
+    assert_eq!(run(&mut game, "cursor 16 0\n"), "not possible now\n");
+
+    assert_eq!(run(&mut game, "win\nlose\n"), "done\nnot possible now\n");
+    assert!(game.status() == GameStatus::Win);
+}
+
+#[test]
+fn rejects_unknown_commands() {
+    let mut game = started_game();
+    assert_eq!(run(&mut game, "fly\n"), "unknown command: fly\n");
+    assert_eq!(run(&mut game, "mine 1\n"), "unknown command: mine 1\n");
+    assert_eq!(run(&mut game, "win now\n"), "unknown command: win now\n");
+}
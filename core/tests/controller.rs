@@ -0,0 +1,237 @@
+//! Controller link driven over a fake I2C bus, one poll per input tick.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use embedded_hal::blocking::i2c::{Read, Write};
+use minesweeper_core::controller::*;
+use minesweeper_core::extension::*;
+use minesweeper_core::game::*;
+use minesweeper_core::settings::Difficulty;
+
+const NUNCHUK_ID: [u8; 6] = [0x00, 0x00, 0xa4, 0x20, 0x00, 0x00];
+const CLASSIC_ID: [u8; 6] = [0x00, 0x00, 0xa4, 0x20, 0x01, 0x01];
+const IDLE_REPORT: [u8; 6] = [0x80, 0x80, 0x80, 0x80, 0x80, 0xff];
+
+#[derive(Debug, PartialEq)]
+struct Nack;
+
+/// Extension device on the other end of the bus.
+#[derive(Default)]
+struct Device {
+    attached: bool,
+    id: [u8; 6],
+    /// Register pointer set by the last write.
+    register: Option<u8>,
+    /// Whether a tick passed since the id was requested.
+    settled: bool,
+    /// Transfers left before the device drops off the bus.
+    nack_in: Option<usize>,
+    transfers: usize,
+}
+
+impl Device {
+    fn transfer(&mut self) -> Result<(), Nack> {
+        self.transfers += 1;
+        if let Some(left) = self.nack_in.as_mut() {
+            if *left == 0 {
+                self.attached = false;
+                self.nack_in = None;
+            } else {
+                *left -= 1;
+            }
+        }
+        if self.attached {
+            Ok(())
+        } else {
+            Err(Nack)
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+struct FakeBus(Rc<RefCell<Device>>);
+
+impl Write for FakeBus {
+    type Error = Nack;
+
+    fn write(&mut self, address: u8, bytes: &[u8]) -> Result<(), Nack> {
+        assert_eq!(address, 0x52);
+        let mut device = self.0.borrow_mut();
+        device.transfer()?;
+        device.register = bytes.first().copied();
+        device.settled = false;
+        Ok(())
+    }
+}
+
+impl Read for FakeBus {
+    type Error = Nack;
+
+    fn read(&mut self, address: u8, buffer: &mut [u8]) -> Result<(), Nack> {
+        assert_eq!(address, 0x52);
+        let mut device = self.0.borrow_mut();
+        device.transfer()?;
+        let bytes = match device.register {
+            Some(0xfa) if device.settled => device.id,
+            Some(0xfa) => [0xff; 6],
+            _ => IDLE_REPORT,
+        };
+        buffer.copy_from_slice(&bytes);
+        Ok(())
+    }
+}
+
+struct Rig {
+    bus: FakeBus,
+    controller: Controller<FakeBus>,
+    game: Minesweeper,
+}
+
+impl Rig {
+    fn new(attached: bool, id: [u8; 6]) -> Self {
+        let bus = FakeBus::default();
+        {
+            let mut device = bus.0.borrow_mut();
+            device.attached = attached;
+            device.id = id;
+        }
+        Self {
+            controller: Controller::new(bus.clone()),
+            bus,
+            game: Minesweeper::new(Difficulty::Easy.config()),
+        }
+    }
+
+    fn device(&self) -> std::cell::RefMut<'_, Device> {
+        self.bus.0.borrow_mut()
+    }
+
+    /// Polls like the input task does, then lets a tick pass on the bus.
+    fn poll(&mut self) -> ControllerEvent {
+        let event = self.controller.poll();
+        match &event {
+            ControllerEvent::Connected => self.game.resume(),
+            ControllerEvent::Disconnected => self.game.pause(Pause::Disconnected),
+            _ => {}
+        }
+        self.device().settled = true;
+        event
+    }
+
+    /// Polls until the controller reports something other than idle.
+    fn poll_until_event(&mut self, limit: usize) -> (usize, ControllerEvent) {
+        for polls in 1..=limit {
+            match self.poll() {
+                ControllerEvent::Idle => {}
+                event => return (polls, event),
+            }
+        }
+        panic!("no event after {} polls", limit);
+    }
+}
+
+fn is_input(event: &ControllerEvent) -> bool {
+    matches!(event, ControllerEvent::Input(_))
+}
+
+#[test]
+fn probe_waits_a_tick_before_reading_id() {
+    let mut rig = Rig::new(true, NUNCHUK_ID);
+    assert!(matches!(rig.poll(), ControllerEvent::Idle));
+    assert!(matches!(rig.poll(), ControllerEvent::Connected));
+    assert!(matches!(
+        rig.poll(),
+        ControllerEvent::Input(Reading::Nunchuk(_))
+    ));
+}
+
+#[test]
+fn probe_detects_classic_controller() {
+    let mut rig = Rig::new(true, CLASSIC_ID);
+    rig.poll_until_event(2);
+    assert!(matches!(
+        rig.poll(),
+        ControllerEvent::Input(Reading::Classic(_))
+    ));
+}
+
+#[test]
+fn probe_failure_pauses_game() {
+    let mut rig = Rig::new(false, NUNCHUK_ID);
+    assert!(matches!(rig.poll(), ControllerEvent::Disconnected));
+    assert!(rig.game.paused() == Some(Pause::Disconnected));
+
+    let mut rig = Rig::new(true, [0xff; 6]);
+    let (polls, event) = rig.poll_until_event(2);
+    assert_eq!(polls, 2);
+    assert!(matches!(event, ControllerEvent::Disconnected), "unknown id");
+}
+
+#[test]
+fn nack_mid_session_disconnects() {
+    let mut rig = Rig::new(true, NUNCHUK_ID);
+    rig.poll_until_event(2);
+    for _ in 0..3 {
+        assert!(is_input(&rig.poll()));
+    }
+
+    // Dropping off between reading the report and starting the next sample
+    rig.device().nack_in = Some(1);
+    assert!(matches!(rig.poll(), ControllerEvent::Disconnected));
+    assert!(rig.game.paused() == Some(Pause::Disconnected));
+}
+
+#[test]
+fn retries_every_ten_ticks_while_disconnected() {
+    let mut rig = Rig::new(false, NUNCHUK_ID);
+    rig.poll();
+    for _ in 0..3 {
+        let before = rig.device().transfers;
+        for _ in 0..Controller::<FakeBus>::RETRY_TICKS {
+            assert!(matches!(rig.poll(), ControllerEvent::Idle));
+        }
+        assert_eq!(rig.device().transfers, before, "bus touched while waiting");
+        assert!(matches!(rig.poll(), ControllerEvent::Idle));
+        assert_eq!(rig.device().transfers, before + 1, "one probe per retry");
+    }
+}
+
+#[test]
+fn reconnect_resumes_game() {
+    let mut rig = Rig::new(true, NUNCHUK_ID);
+    rig.poll_until_event(2);
+    rig.device().attached = false;
+    assert!(matches!(rig.poll(), ControllerEvent::Disconnected));
+    assert!(rig.game.paused() == Some(Pause::Disconnected));
+
+    rig.device().attached = true;
+    let retry = Controller::<FakeBus>::RETRY_TICKS as usize;
+    let (polls, event) = rig.poll_until_event(retry + 2);
+    assert!(matches!(event, ControllerEvent::Connected));
+    assert_eq!(polls, retry + 2, "retry countdown, init, then identify");
+    assert!(rig.game.paused().is_none());
+    assert!(is_input(&rig.poll()));
+}
+
+#[test]
+fn device_lost_while_identifying_keeps_retrying() {
+    let mut rig = Rig::new(true, NUNCHUK_ID);
+    rig.poll_until_event(2);
+    rig.device().attached = false;
+    rig.poll();
+
+    // Answers the init sequence, then drops before the id is read
+    rig.device().attached = true;
+    rig.device().nack_in = Some(3);
+    let retry = Controller::<FakeBus>::RETRY_TICKS as usize;
+    for _ in 0..retry + 2 {
+        assert!(matches!(rig.poll(), ControllerEvent::Idle));
+    }
+    assert!(rig.game.paused() == Some(Pause::Disconnected));
+
+    rig.device().attached = true;
+    let (_, event) = rig.poll_until_event(retry + 2);
+    assert!(matches!(event, ControllerEvent::Connected));
+    assert!(rig.game.paused().is_none());
+}
@@ -0,0 +1,171 @@
+//! Idle policy on a fake millisecond clock, polled the way the input task does.
+
+use minesweeper_core::game::*;
+use minesweeper_core::idle::*;
+use minesweeper_core::settings::Difficulty;
+
+/// Input task state: the clock, the idle policy and the game it routes input to.
+struct Device {
+    idle: IdleMonitor,
+    uptime: u32,
+    game: Minesweeper,
+}
+
+impl Device {
+    fn new(uptime: u32) -> Self {
+        let mut game = Minesweeper::new(Difficulty::Easy.config());
+        game.seed_random(1337);
+        Self {
+            idle: IdleMonitor::new(IdlePolicy::default(), uptime),
+            uptime,
+            game,
+        }
+    }
+
+    fn poll(&mut self) -> Option<PowerState> {
+        let game = &mut self.game;
+        self.idle.poll(&mut self.uptime, || game.tick())
+    }
+
+    /// Polls until the power state changes, returns it with the idle time.
+    fn poll_until_change(&mut self) -> (PowerState, u32) {
+        for _ in 0..10_000 {
+            if let Some(state) = self.poll() {
+                return (state, self.idle.idle_ms(self.uptime));
+            }
+        }
+        panic!("power state never changed");
+    }
+
+    /// Presses buttons on the next poll, returns how many the game saw.
+    fn press_all(&mut self, buttons: impl IntoIterator<Item = GameButton>) -> usize {
+        self.poll();
+        let mut seen = 0;
+        for button in buttons {
+            let game = &mut self.game;
+            self.idle.input(self.uptime, || {
+                game.button_click(button);
+                seen += 1;
+            });
+        }
+        seen
+    }
+
+    /// Receives a remote command on the next poll.
+    fn remote(&mut self) {
+        self.poll();
+        self.idle.remote(self.uptime);
+    }
+
+    /// Presses a button on the next poll, returns whether the game saw it.
+    fn press(&mut self, button: GameButton) -> bool {
+        self.press_all([button]) == 1
+    }
+}
+
+#[test]
+fn blanks_after_a_minute_and_sleeps_after_three() {
+    let mut device = Device::new(0);
+    assert_eq!(device.idle.state(), PowerState::Active);
+    assert_eq!(device.poll_until_change(), (PowerState::Blank, 60_000));
+    assert_eq!(device.poll_until_change(), (PowerState::Sleep, 180_000));
+
+    // Sleeping stays asleep and polls once a second
+    for _ in 0..100 {
+        assert_eq!(device.poll(), None);
+    }
+    assert_eq!(device.idle.idle_ms(device.uptime), 280_000);
+}
+
+#[test]
+fn input_postpones_blanking() {
+    let mut device = Device::new(0);
+    for _ in 0..500 {
+        device.poll();
+    }
+    assert!(device.press(GameButton::DPad(Dir::Right)));
+    assert_eq!(device.poll_until_change(), (PowerState::Blank, 60_000));
+    assert_eq!(device.uptime, 50_000 + 100 + 60_000);
+}
+
+#[test]
+fn wake_swallows_first_press() {
+    for idle_until in [PowerState::Blank, PowerState::Sleep] {
+        let mut device = Device::new(0);
+        while device.idle.state() != idle_until {
+            device.poll();
+        }
+
+        let cursor = device.game.board().cursor();
+        assert!(!device.press(GameButton::DPad(Dir::Right)));
+        assert_eq!(device.idle.state(), PowerState::Active);
+        assert_eq!(device.game.board().cursor(), cursor, "waking press moved");
+
+        assert!(device.press(GameButton::DPad(Dir::Right)));
+        assert_ne!(device.game.board().cursor(), cursor);
+    }
+}
+
+#[test]
+fn game_clock_stops_while_blank() {
+    let mut device = Device::new(0);
+    assert!(device.press(GameButton::A));
+    assert!(device.game.status() == GameStatus::Playing);
+
+    device.poll_until_change();
+    // The clock starts on the poll after the first click
+    let blanked_at = device.game.elapsed_secs();
+    assert_eq!(blanked_at, 59);
+    device.poll_until_change();
+    for _ in 0..600 {
+        device.poll();
+    }
+    assert_eq!(
+        device.game.elapsed_secs(),
+        blanked_at,
+        "clock ran while blank"
+    );
+
+    assert!(!device.press(GameButton::DPad(Dir::Left)));
+    for _ in 0..10 * Minesweeper::TICKS_PER_SECOND {
+        device.poll();
+    }
+    assert_eq!(device.game.elapsed_secs(), blanked_at + 10);
+}
+
+#[test]
+fn clock_wraps_around() {
+    let start = u32::MAX - 30_000;
+    let mut device = Device::new(start);
+    assert_eq!(device.poll_until_change(), (PowerState::Blank, 60_000));
+    assert!(device.uptime < start, "clock did not wrap");
+    assert_eq!(device.uptime, start.wrapping_add(60_000));
+
+    assert!(!device.press(GameButton::B));
+    assert_eq!(device.poll_until_change(), (PowerState::Blank, 60_000));
+}
+
+#[test]
+fn wake_swallows_the_rest_of_its_poll() {
+    let mut device = Device::new(0);
+    while device.idle.state() != PowerState::Blank {
+        device.poll();
+    }
+
+    let right = || GameButton::DPad(Dir::Right);
+    assert_eq!(device.press_all([right(), right()]), 0);
+    assert_eq!(device.press_all([right(), right()]), 2);
+}
+
+#[test]
+fn remote_link_keeps_the_serial_port_awake() {
+    let mut device = Device::new(0);
+    device.remote();
+    assert_eq!(device.poll_until_change(), (PowerState::Blank, 60_000));
+    assert_eq!(device.poll_until_change(), (PowerState::Sleep, 1_800_000));
+
+    // Controller input alone lets it sleep as usual
+    assert!(!device.press(GameButton::B));
+    assert_eq!(device.poll_until_change(), (PowerState::Blank, 60_000));
+    assert_eq!(device.poll_until_change(), (PowerState::Sleep, 180_000));
+}
@@ -0,0 +1,370 @@
+//! Input mapping: stick calibration, dead zone and tilt, fed raw controller readings.
+
+use minesweeper_core::extension::*;
+use minesweeper_core::game::*;
+use minesweeper_core::input::*;
+use minesweeper_core::menu::*;
+use minesweeper_core::settings::*;
+
+fn stick(x: u8, y: u8) -> NunchukReading {
+    NunchukReading {
+        joystick_x: x,
+        joystick_y: y,
+        ..NunchukReading::default()
+    }
+}
+
+/// Runs a full calibration: release, center, then the given extents.
+fn calibrate(center: (u8, u8), extents: &[(u8, u8)]) -> Settings {
+    let mut calibrator = Calibrator::default();
+    let mut held = stick(center.0, center.1);
+    held.button_c = true;
+    held.button_z = true;
+    assert!(!calibrator.sample(&held));
+    for _ in 0..9 {
+        assert!(!calibrator.sample(&stick(center.0, center.1)));
+    }
+    for &(x, y) in extents {
+        assert!(!calibrator.sample(&stick(x, y)));
+    }
+    let mut done = stick(center.0, center.1);
+    done.button_z = true;
+    assert!(calibrator.sample(&done));
+
+    let mut settings = Settings::default();
+    calibrator.apply(&mut settings);
+    settings
+}
+
+#[test]
+fn calibration_measures_center_and_reach() {
+    let settings = calibrate((130, 120), &[(30, 120), (230, 120), (130, 20), (130, 200)]);
+    assert!(settings.x_axis == AxisCalibration::from_extents(130, 30, 230));
+    assert_eq!(settings.x_axis.center, 130);
+    assert_eq!(settings.x_axis.threshold, 100);
+    assert_eq!(settings.y_axis.center, 120);
+    assert_eq!(settings.y_axis.threshold, 80);
+}
+
+#[test]
+fn calibration_falls_back_without_extents() {
+    let settings = calibrate((130, 120), &[]);
+    assert!(settings.x_axis == AxisCalibration::default());
+    assert!(settings.y_axis == AxisCalibration::default());
+
+    // Only nudged sideways, the vertical axis is still usable
+    let settings = calibrate((130, 120), &[(140, 120), (120, 30), (125, 210)]);
+    assert!(settings.x_axis == AxisCalibration::default());
+    assert_eq!(settings.y_axis.threshold, 90);
+}
+
+#[test]
+fn dead_zone_is_set_from_settings_menu() {
+    let mut menu = Menu::new();
+    let mut settings = Settings::default();
+    let settings_item = Screen::Title
+        .items()
+        .iter()
+        .position(|&item| item == MenuItem::Settings)
+        .unwrap();
+    for _ in 0..settings_item {
+        menu.handle(Action::Move(Dir::Down), &mut settings);
+    }
+    menu.handle(Action::Open, &mut settings);
+    let dead_zone = Screen::Settings
+        .items()
+        .iter()
+        .position(|&item| item == MenuItem::DeadZone)
+        .unwrap();
+    for _ in 0..dead_zone {
+        menu.handle(Action::Move(Dir::Down), &mut settings);
+    }
+
+    assert_eq!(settings.dead_zone, 50);
+    menu.handle(Action::Move(Dir::Left), &mut settings);
+    menu.handle(Action::Move(Dir::Left), &mut settings);
+    assert_eq!(settings.dead_zone, 30);
+    menu.handle(Action::Open, &mut settings);
+    assert_eq!(settings.dead_zone, 40);
+    for _ in 0..5 {
+        menu.handle(Action::Move(Dir::Right), &mut settings);
+    }
+    assert_eq!(settings.dead_zone, Settings::MAX_DEAD_ZONE);
+    menu.handle(Action::Move(Dir::Right), &mut settings);
+    assert_eq!(settings.dead_zone, Settings::DEAD_ZONE_STEP, "wraps around");
+
+    assert!(menu.handle(Action::Flag, &mut settings) == MenuEvent::SaveSettings);
+    let saved = Settings::decode(&settings.encode()).unwrap();
+    assert_eq!(saved.dead_zone, Settings::DEAD_ZONE_STEP);
+}
+
+#[test]
+fn stick_moves_past_dead_zone() {
+    let mut settings = Settings::default();
+    // 40% of the default reach of 128
+    let nudge = stick(127 + 52, 127);
+    assert_eq!(stick_directions(&settings, &nudge), [None, None]);
+    settings.dead_zone = 30;
+    assert_eq!(
+        stick_directions(&settings, &nudge),
+        [Some(Dir::Right), None]
+    );
+}
+
+fn tilt(x: u16, y: u16) -> NunchukReading {
+    NunchukReading {
+        accel_x: x,
+        accel_y: y,
+        ..NunchukReading::default()
+    }
+}
+
+/// Feeds a sequence of X tilts around the 512 center, returns the moves per reading.
+fn tilt_moves(settings: &Settings, tilts: &[i16]) -> Vec<[Option<Dir>; 2]> {
+    let mut tracker = TiltTracker::default();
+    tilts
+        .iter()
+        .map(|&x| tracker.directions(settings, &tilt((512 + x) as u16, 512)))
+        .collect()
+}
+
+fn steps(moves: &[[Option<Dir>; 2]]) -> usize {
+    moves.iter().flatten().flatten().count()
+}
+
+#[test]
+fn tilt_engages_at_60_and_releases_at_40() {
+    let settings = Settings::default();
+    let moves = tilt_moves(&settings, &[0, 59, 60]);
+    assert_eq!(
+        moves,
+        [[None, None], [None, None], [Some(Dir::Right), None]]
+    );
+    let moves = tilt_moves(&settings, &[-60]);
+    assert_eq!(moves, [[Some(Dir::Left), None]]);
+
+    // Engaged axes keep counting down to 40, then need 60 again
+    let mut tracker = TiltTracker::default();
+    let mut step = |x: i16| tracker.directions(&settings, &tilt((512 + x) as u16, 512));
+    assert_eq!(step(60), [Some(Dir::Right), None]);
+    for _ in 0..47 {
+        assert_eq!(step(45), [None, None]);
+    }
+    assert_eq!(step(45), [Some(Dir::Right), None], "still engaged at 45");
+    assert_eq!(step(39), [None, None]);
+    for _ in 0..100 {
+        assert_eq!(step(59), [None, None], "released until 60 again");
+    }
+    assert_eq!(step(60), [Some(Dir::Right), None]);
+}
+
+#[test]
+fn tilt_does_not_chatter_around_threshold() {
+    let settings = Settings::default();
+    let wobble: Vec<i16> = (0..100)
+        .map(|tick| if tick % 2 == 0 { 61 } else { 58 })
+        .collect();
+    let moves = tilt_moves(&settings, &wobble);
+    // One step on engaging, then the wobble adds 21 and 18 per pair of readings
+    assert_eq!(steps(&moves), 1 + (99 / 2 * 39 + 18) / 240);
+
+    let wobble: Vec<i16> = (0..100)
+        .map(|tick| if tick % 2 == 0 { 41 } else { 39 })
+        .collect();
+    let moves = tilt_moves(&settings, &[&[60][..], &wobble].concat());
+    assert_eq!(steps(&moves), 1, "released below 40 without stepping");
+}
+
+#[test]
+fn tilt_repeat_rate_grows_with_angle() {
+    let settings = Settings::default();
+    // Each reading adds the tilt past 40 and steps every 240 of it
+    for (angle, expected) in [(70, 1 + 95 * 30 / 240), (100, 1 + 95 * 60 / 240), (280, 96)] {
+        let moves = tilt_moves(&settings, &[angle; 96]);
+        assert_eq!(steps(&moves), expected, "tilt {}", angle);
+    }
+}
+
+#[test]
+fn tilt_recenters_both_axes() {
+    let settings = Settings {
+        diagonal: true,
+        ..Settings::default()
+    };
+    let mut tracker = TiltTracker::default();
+    // Pitching forward lowers the Y reading and moves up
+    assert_eq!(
+        tracker.directions(&settings, &tilt(612, 412)),
+        [Some(Dir::Right), Some(Dir::Up)]
+    );
+    assert_eq!(tracker.directions(&settings, &tilt(512, 512)), [None, None]);
+    assert_eq!(
+        tracker.directions(&settings, &tilt(412, 612)),
+        [Some(Dir::Left), Some(Dir::Down)],
+        "engages again right after centering"
+    );
+}
+
+fn buttons(z: bool, c: bool) -> Reading {
+    Reading::Nunchuk(NunchukReading {
+        joystick_x: 127,
+        joystick_y: 127,
+        button_z: z,
+        button_c: c,
+        ..NunchukReading::default()
+    })
+}
+
+/// Feeds one reading per `(z, c)` pair, returns every action emitted.
+fn feed(mapper: &mut InputMapper, settings: &Settings, readings: &[(bool, bool)]) -> Vec<Action> {
+    let mut actions = Vec::new();
+    for &(z, c) in readings {
+        mapper.map(settings, &buttons(z, c), |action| actions.push(action));
+    }
+    actions
+}
+
+/// Presses and releases the buttons, returns every action emitted.
+fn press(mapper: &mut InputMapper, settings: &Settings, z: bool, c: bool) -> Vec<Action> {
+    feed(mapper, settings, &[(z, c), (false, false)])
+}
+
+fn chord_with(chord: ChordButton) -> Settings {
+    let mut settings = Settings::default();
+    settings.action_map.chord = chord;
+    settings
+}
+
+#[test]
+fn one_action_per_press_for_every_chord_button() {
+    let both_buttons: [(ChordButton, &[Action]); 3] = [
+        (ChordButton::Both, &[Action::Chord]),
+        (ChordButton::Open, &[]),
+        (ChordButton::Flag, &[]),
+    ];
+    for (chord, both) in both_buttons {
+        let settings = chord_with(chord);
+        let mut mapper = InputMapper::default();
+        assert_eq!(press(&mut mapper, &settings, true, false), [Action::Open]);
+        assert_eq!(press(&mut mapper, &settings, false, true), [Action::Flag]);
+        assert_eq!(press(&mut mapper, &settings, true, true), both);
+    }
+}
+
+#[test]
+fn buttons_act_on_release() {
+    let settings = Settings::default();
+    let mut mapper = InputMapper::default();
+    assert_eq!(feed(&mut mapper, &settings, &[(true, false); 5]), []);
+    assert_eq!(
+        feed(&mut mapper, &settings, &[(false, false)]),
+        [Action::Open]
+    );
+    assert_eq!(feed(&mut mapper, &settings, &[(false, false)]), []);
+}
+
+#[test]
+fn staggered_chord_is_one_chord() {
+    let settings = chord_with(ChordButton::Both);
+    let staggered: [&[(bool, bool)]; 4] = [
+        // Pressed a tick apart, released together
+        &[(true, false), (true, true), (false, false)],
+        &[(false, true), (true, true), (false, false)],
+        // Pressed together, released a tick apart
+        &[(true, true), (true, false), (false, false)],
+        &[(true, true), (false, true), (false, false)],
+    ];
+    for readings in staggered {
+        let mut mapper = InputMapper::default();
+        assert_eq!(
+            feed(&mut mapper, &settings, readings),
+            [Action::Chord],
+            "{readings:?}"
+        );
+    }
+
+    // Both pressed and released a tick apart, with other mappings too
+    for chord in [ChordButton::Both, ChordButton::Open, ChordButton::Flag] {
+        let settings = chord_with(chord);
+        let mut mapper = InputMapper::default();
+        let readings = [(true, false), (true, true), (false, true), (false, false)];
+        let expected: &[Action] = match chord {
+            ChordButton::Both => &[Action::Chord],
+            _ => &[],
+        };
+        assert_eq!(feed(&mut mapper, &settings, &readings), expected);
+    }
+}
+
+#[test]
+fn menu_combo_fires_nothing_else() {
+    for chord in [ChordButton::Both, ChordButton::Open, ChordButton::Flag] {
+        let settings = chord_with(chord);
+        let mut mapper = InputMapper::default();
+        let mut readings = vec![(true, false)];
+        readings.extend([(true, true); 12]);
+        readings.extend([(false, true), (false, false)]);
+        assert_eq!(feed(&mut mapper, &settings, &readings), [Action::Menu]);
+    }
+}
+
+#[test]
+fn swallowed_press_does_nothing_on_release() {
+    let settings = Settings::default();
+    let mut mapper = InputMapper::default();
+    assert_eq!(feed(&mut mapper, &settings, &[(true, false)]), []);
+    mapper.swallow();
+    assert_eq!(feed(&mut mapper, &settings, &[(false, false)]), []);
+    assert_eq!(press(&mut mapper, &settings, true, false), [Action::Open]);
+}
+
+#[test]
+fn chord_button_chords_only_on_opened_tiles() {
+    let mut game = Minesweeper::new(Difficulty::Easy.config());
+    game.seed_random(1337);
+    let settings = chord_with(ChordButton::Open);
+    assert_eq!(
+        resolve_chord(&settings, Action::Open, &game),
+        Action::Open,
+        "first click opens"
+    );
+    game.button_click(GameButton::A);
+    assert!(game.status() == GameStatus::Playing);
+    assert_eq!(resolve_chord(&settings, Action::Open, &game), Action::Chord);
+    assert_eq!(resolve_chord(&settings, Action::Flag, &game), Action::Flag);
+
+    let settings = chord_with(ChordButton::Flag);
+    assert_eq!(resolve_chord(&settings, Action::Flag, &game), Action::Chord);
+    assert_eq!(resolve_chord(&settings, Action::Open, &game), Action::Open);
+
+    let settings = chord_with(ChordButton::Both);
+    assert_eq!(resolve_chord(&settings, Action::Open, &game), Action::Open);
+}
+
+/// Classic controller reading with `held` buttons down, the report is active low.
+fn classic(held: u16) -> Reading {
+    let [high, low] = (!held).to_be_bytes();
+    Reading::Classic(ClassicReading::from(&[0, 0, 0, 0, high, low]))
+}
+
+fn map_classic(mapper: &mut InputMapper, settings: &Settings, held: u16) -> Vec<Action> {
+    let mut actions = Vec::new();
+    mapper.map(settings, &classic(held), |action| actions.push(action));
+    actions
+}
+
+#[test]
+fn left_handed_classic_swaps_dpad_and_face_buttons() {
+    let mut settings = Settings::default();
+    let mut mapper = InputMapper::default();
+    let up = map_classic(&mut mapper, &settings, ClassicReading::DPAD_UP);
+    assert_eq!(up, [Action::Move(Dir::Up)]);
+    map_classic(&mut mapper, &settings, ClassicReading::BUTTON_A);
+    assert_eq!(map_classic(&mut mapper, &settings, 0), [Action::Open]);
+
+    settings.action_map.left_handed = true;
+    let up = map_classic(&mut mapper, &settings, ClassicReading::BUTTON_X);
+    assert_eq!(up, [Action::Move(Dir::Up)]);
+    map_classic(&mut mapper, &settings, ClassicReading::DPAD_LEFT);
+    assert_eq!(map_classic(&mut mapper, &settings, 0), [Action::Open]);
+}
@@ -0,0 +1,258 @@
+//! Property tests for the board and game rules over random seeds and button sequences.
+
+use klaptik::Point;
+use minesweeper_core::board::*;
+use minesweeper_core::game::*;
+use minesweeper_core::tiles::TileSize;
+use proptest::prelude::*;
+
+mod common;
+
+use common::move_to;
+
+fn config() -> impl Strategy<Value = GameConfig> {
+    (1..=Board::MAX_WIDTH, 1..=Board::MAX_HEIGHT, 0..3usize).prop_flat_map(
+        |(width, height, tiles)| {
+            let tiles = [TileSize::Large, TileSize::Medium, TileSize::Small][tiles];
+            (0..width * height).prop_map(move |bombs| GameConfig {
+                width,
+                height,
+                bombs,
+                tiles,
+            })
+        },
+    )
+}
+
+fn button() -> impl Strategy<Value = GameButton> {
+    (0..7u8).prop_map(|button| match button {
+        0 => GameButton::A,
+        1 => GameButton::B,
+        2 => GameButton::Chord,
+        3 => GameButton::DPad(Dir::Up),
+        4 => GameButton::DPad(Dir::Right),
+        5 => GameButton::DPad(Dir::Down),
+        _ => GameButton::DPad(Dir::Left),
+    })
+}
+
+fn positions(board: &Board) -> impl Iterator<Item = Point> {
+    let (width, height) = (board.width(), board.height());
+    (0..height).flat_map(move |y| (0..width).map(move |x| Point::new(x as i32, y as i32)))
+}
+
+fn is_bomb(tile: Tile) -> bool {
+    tile.content() == TileContent::Bomb
+}
+
+/// Mine count matches the config and every hint matches its neighbourhood.
+fn check_layout(game: &Minesweeper) -> Result<(), TestCaseError> {
+    let board = game.board();
+    let bombs = board.tiles().iter().filter(|&&tile| is_bomb(tile)).count();
+    prop_assert_eq!(bombs, game.config().bombs);
+
+    for pos in positions(board) {
+        if let TileContent::Hint(hint) = board.tile_at(pos).content() {
+            let around = board
+                .neighbors(pos)
+                .filter(|&pos| is_bomb(board.tile_at(pos)))
+                .count();
+            prop_assert_eq!(hint as usize, around, "wrong hint at {:?}", pos);
+        }
+    }
+    Ok(())
+}
+
+/// 3BV the slow way: flood each opening separately, then count hints away from all openings.
+fn reference_three_bv(board: &Board) -> usize {
+    let is_empty = |pos| board.tile_at(pos).content() == TileContent::Hint(0);
+    let mut seen = Vec::new();
+    let mut openings = 0;
+    for start in positions(board).filter(|&pos| is_empty(pos)) {
+        if seen.contains(&start) {
+            continue;
+        }
+        openings += 1;
+        let mut todo = vec![start];
+        while let Some(pos) = todo.pop() {
+            if seen.contains(&pos) {
+                continue;
+            }
+            seen.push(pos);
+            todo.extend(board.neighbors(pos).filter(|&pos| is_empty(pos)));
+        }
+    }
+    let isolated = positions(board)
+        .filter(|&pos| !is_empty(pos) && !is_bomb(board.tile_at(pos)))
+        .filter(|&pos| !board.neighbors(pos).any(is_empty))
+        .count();
+    openings + isolated
+}
+
+proptest! {
+    #[test]
+    fn neighbors_stay_on_board(
+        (width, height, x, y) in (1..=Board::MAX_WIDTH, 1..=Board::MAX_HEIGHT)
+            .prop_flat_map(|(w, h)| (Just(w), Just(h), 0..w, 0..h))
+    ) {
+        let board = Board::new(width, height);
+        let origin = Point::new(x as i32, y as i32);
+        let neighbors: Vec<Point> = board.neighbors(origin).collect();
+
+        let expected = positions(&board)
+            .filter(|pos| pos != &origin)
+            .filter(|pos| (pos.x - origin.x).abs() <= 1 && (pos.y - origin.y).abs() <= 1)
+            .count();
+        prop_assert_eq!(neighbors.len(), expected);
+        for pos in neighbors {
+            prop_assert!(board.contains(pos));
+        }
+    }
+
+    #[test]
+    fn first_click_plants_exact_layout(
+        config in config(),
+        seed in any::<u32>(),
+        x in 0..Board::MAX_WIDTH,
+        y in 0..Board::MAX_HEIGHT,
+    ) {
+        let mut game = Minesweeper::new(config);
+        game.seed_random(seed);
+        let first = Point::new((x % config.width) as i32, (y % config.height) as i32);
+        move_to(&mut game, first);
+        game.button_click(GameButton::A);
+
+        prop_assert!(!is_bomb(game.board().tile_at(first)), "first click hit a mine");
+        prop_assert!(game.board().tile_at(first).status() == TileStatus::Opened);
+        check_layout(&game)?;
+    }
+
+    #[test]
+    fn three_bv_matches_reference(config in config(), seed in any::<u32>()) {
+        let mut game = Minesweeper::new(config);
+        game.seed_random(seed);
+        game.button_click(GameButton::A);
+
+        let board = game.board();
+        prop_assert_eq!(board.three_bv(), reference_three_bv(board));
+    }
+
+    #[test]
+    fn random_play_keeps_invariants(
+        config in config(),
+        seed in any::<u32>(),
+        buttons in prop::collection::vec(button(), 1..200),
+    ) {
+        let mut game = Minesweeper::new(config);
+        game.seed_random(seed);
+
+        for button in buttons {
+            let before = game.board().tiles().to_vec();
+            let cursor = game.board().cursor();
+            let restart = button == GameButton::A && !game.in_progress();
+            let open = button == GameButton::A;
+            let flag = button == GameButton::B;
+
+            game.button_click(button);
+            let board = game.board();
+
+            prop_assert!(board.contains(board.cursor()), "cursor left the board");
+            if restart {
+                prop_assert!(!is_bomb(board.tile_at(cursor)), "first click hit a mine");
+                check_layout(&game)?;
+                continue;
+            }
+
+            // Opening only reaches tiles connected to the cursor through empty tiles
+            let newly = |pos: Point| {
+                let offset = pos.x as usize + pos.y as usize * board.width();
+                before[offset].status() != TileStatus::Opened
+                    && board.tile_at(pos).status() == TileStatus::Opened
+            };
+            if open {
+                for pos in positions(board).filter(|&pos| pos != cursor && newly(pos)) {
+                    prop_assert!(
+                        board.neighbors(pos).any(|around| {
+                            board.tile_at(around).content() == TileContent::Hint(0)
+                                && (around == cursor || newly(around))
+                        }),
+                        "{:?} opened away from {:?}",
+                        pos,
+                        cursor
+                    );
+                }
+            }
+
+            for (pos, (old, new)) in positions(board).zip(before.iter().zip(board.tiles())) {
+                prop_assert!(old.content() == new.content(), "content changed at {:?}", pos);
+                if old.status() == TileStatus::Opened {
+                    prop_assert!(new.status() == TileStatus::Opened, "tile closed at {:?}", pos);
+                }
+                if flag && pos != cursor {
+                    prop_assert!(old.status() == new.status(), "flag touched {:?}", pos);
+                }
+            }
+        }
+    }
+}
+
+fn closed_empty(board: &Board) -> impl Iterator<Item = Point> + '_ {
+    positions(board).filter(move |&pos| {
+        let tile = board.tile_at(pos);
+        (tile.status(), tile.content()) == (TileStatus::Closed, TileContent::Hint(0))
+    })
+}
+
+#[test]
+fn flood_fill_leaves_earlier_openings_alone() {
+    let config = GameConfig {
+        width: Board::MAX_WIDTH,
+        height: Board::MAX_HEIGHT,
+        bombs: 60,
+        tiles: TileSize::Small,
+    };
+    let mut checked = 0;
+    for seed in 0..200 {
+        let mut game = Minesweeper::new(config);
+        game.seed_random(seed);
+        game.button_click(GameButton::A);
+
+        // A tile skipped by a flood fill because it was flagged at the time
+        let Some(empty) = closed_empty(game.board()).next() else {
+            continue;
+        };
+        let skipped = game.board().neighbors(empty).next().unwrap();
+        move_to(&mut game, skipped);
+        game.button_click(GameButton::B);
+        move_to(&mut game, empty);
+        game.button_click(GameButton::A);
+        move_to(&mut game, skipped);
+        game.button_click(GameButton::B);
+        assert!(game.board().tile_at(skipped).status() == TileStatus::Closed);
+
+        let Some(other) = closed_empty(game.board()).find(|&pos| pos != skipped) else {
+            continue;
+        };
+        let before = game.board().tiles().to_vec();
+        move_to(&mut game, other);
+        game.button_click(GameButton::A);
+        let board = game.board();
+        let reached = board.neighbors(skipped).any(|pos| {
+            let offset = pos.x as usize + pos.y as usize * board.width();
+            before[offset].status() == TileStatus::Closed
+                && board.tile_at(pos).status() == TileStatus::Opened
+                && board.tile_at(pos).content() == TileContent::Hint(0)
+        });
+        if !reached {
+            assert!(
+                board.tile_at(skipped).status() == TileStatus::Closed,
+                "seed {}: opening {:?} opened {:?}",
+                seed,
+                other,
+                skipped
+            );
+            checked += 1;
+        }
+    }
+    assert!(checked > 10, "only {} boards had two openings", checked);
+}
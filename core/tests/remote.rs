@@ -0,0 +1,94 @@
+//! Remote control protocol, driven byte by byte like the serial port does.
+
+use minesweeper_core::game::*;
+use minesweeper_core::remote::*;
+use minesweeper_core::settings::Difficulty;
+
+fn run(game: &mut Minesweeper, input: &str) -> String {
+    let mut remote = Remote::new();
+    let mut out = String::new();
+    for byte in input.bytes() {
+        match remote.feed(byte) {
+            Some(Ok(command)) => execute(command, game, &mut out).unwrap(),
+            Some(Err(err)) => reply_error(err, &mut out).unwrap(),
+            None => {}
+        }
+    }
+    out
+}
+
+fn new_game() -> Minesweeper {
+    Minesweeper::new(Difficulty::Easy.config())
+}
+
+#[test]
+fn buttons_move_cursor_and_open() {
+    let mut game = new_game();
+    let start = game.board().cursor();
+    let out = run(&mut game, "right\r\nright\ndown\nopen\nstatus\n");
+    let cursor = game.board().cursor();
+    assert_eq!((cursor.x, cursor.y), (start.x + 2, start.y + 1));
+    assert!(game.status() != GameStatus::Bootstrap);
+    assert!(out.starts_with("ok\nok\nok\nok\nstatus "));
+    assert!(out.ends_with(&format!(" 0 {} {}\nok\n", cursor.x, cursor.y)));
+}
+
+#[test]
+fn new_game_is_reproducible() {
+    let mut first = new_game();
+    let mut second = new_game();
+    let script = "new 1234 20\nopen\nboard\n";
+    let board = run(&mut first, script);
+    assert_eq!(board, run(&mut second, script));
+    assert_eq!(first.config().bombs, 20);
+
+    let mut lines = board.lines().skip(2);
+    assert_eq!(lines.next(), Some("board 16 6"));
+    let rows: Vec<&str> = lines.by_ref().take(6).collect();
+    assert!(rows.iter().all(|row| row.len() == 16));
+    assert_eq!(lines.next(), Some("ok"));
+}
+
+#[test]
+fn controller_open_keeps_remote_seed() {
+    let mut game = new_game();
+    run(&mut game, "new 1234 20\n");
+    // The input task seeds every open from the timer
+    game.seed_entropy(999);
+    game.button_click(GameButton::A);
+    assert_eq!(game.board_seed(), 1234);
+
+    // Later boards take the entropy again
+    game.restart(Difficulty::Easy.config());
+    game.seed_entropy(999);
+    game.button_click(GameButton::A);
+    assert_eq!(game.board_seed(), 999);
+}
+
+#[test]
+fn board_hides_closed_tiles() {
+    let mut game = new_game();
+    let out = run(&mut game, "board\n");
+    let closed = "#".repeat(16);
+    let expected = format!("board 16 6\n{}ok\n", format!("{}\n", closed).repeat(6));
+    assert_eq!(out, expected);
+}
+
+#[test]
+fn rejects_bad_input() {
+    let mut game = new_game();
+    assert_eq!(run(&mut game, "jump\n"), "error unknown command\n");
+    assert_eq!(run(&mut game, "new 1\n"), "error invalid argument\n");
+    assert_eq!(run(&mut game, "new x 5\n"), "error invalid argument\n");
+    assert_eq!(run(&mut game, "status now\n"), "error invalid argument\n");
+    assert_eq!(run(&mut game, "new 1 96\n"), "error invalid argument\n");
+    assert_eq!(
+        run(&mut game, "new 4294967296 5\n"),
+        "error invalid argument\n"
+    );
+    assert_eq!(run(&mut game, "new 1 -5\n"), "error invalid argument\n");
+    assert_eq!(run(&mut game, &"x".repeat(100)), "");
+    let out = run(&mut game, &format!("{}\nstatus\n", "x".repeat(100)));
+    assert!(out.starts_with("error line too long\nstatus ready 8 0 "));
+    assert_eq!(run(&mut game, "\n  \r\n"), "");
+}
@@ -0,0 +1,102 @@
+//! Solver bot and demo mode played through whole games on seeded boards.
+
+use klaptik::Point;
+use minesweeper_core::board::*;
+use minesweeper_core::demo::*;
+use minesweeper_core::game::*;
+use minesweeper_core::player::*;
+use minesweeper_core::settings::Difficulty;
+use minesweeper_core::solver::*;
+
+const GAMES: u32 = 50;
+
+/// Plays a game to the end by walking the cursor like a person would.
+fn play(game: &mut Minesweeper, solver: &mut Solver) {
+    let limit = 4 * game.board().tiles().len();
+    for _ in 0..limit {
+        let next = match solver.next_move(&View::new(game)) {
+            Some(next) => next,
+            None => return,
+        };
+        if let Move::Flag(pos) = next {
+            let tile = game.board().tile_at(pos);
+            assert!(tile.content() == TileContent::Bomb, "flagged a safe tile");
+        }
+        for button in next.buttons(game.board().cursor()) {
+            game.button_click(button);
+        }
+        assert_eq!(game.board().cursor(), next.target());
+    }
+    panic!("game did not finish");
+}
+
+fn new_game(difficulty: Difficulty, seed: u32) -> Minesweeper {
+    let mut game = Minesweeper::new(difficulty.config());
+    game.seed_random(seed);
+    game
+}
+
+#[test]
+fn buttons_walk_to_target() {
+    let next = Move::Chord(Point::new(1, 3));
+    let buttons: Vec<GameButton> = next.buttons(Point::new(3, 2)).collect();
+    assert!(
+        buttons
+            == [
+                GameButton::DPad(Dir::Left),
+                GameButton::DPad(Dir::Left),
+                GameButton::DPad(Dir::Down),
+                GameButton::Chord,
+            ]
+    );
+}
+
+#[test]
+fn solver_finishes_games() {
+    for difficulty in Difficulty::ALL {
+        for seed in 1..=GAMES {
+            let mut game = new_game(difficulty, seed);
+            play(&mut game, &mut Solver::new());
+            assert!(matches!(
+                game.status(),
+                GameStatus::Win | GameStatus::GameOver
+            ));
+        }
+    }
+}
+
+#[test]
+fn solver_wins_most_easy_games() {
+    let mut wins = 0;
+    for seed in 1..=GAMES {
+        let mut game = new_game(Difficulty::Easy, seed);
+        play(&mut game, &mut Solver::new());
+        if game.status() == GameStatus::Win {
+            wins += 1;
+        }
+    }
+    assert!(wins * 4 >= GAMES * 3, "won only {} of {}", wins, GAMES);
+}
+
+#[test]
+fn demo_deals_new_board_after_game() {
+    let mut game = new_game(Difficulty::Easy, 7);
+    let mut demo = Demo::new(Solver::new());
+    demo.step(&mut game);
+    assert!(
+        game.status() == GameStatus::Bootstrap,
+        "demo is not running yet"
+    );
+
+    demo.start();
+    let mut finished = false;
+    for _ in 0..10_000 {
+        demo.step(&mut game);
+        match game.status() {
+            GameStatus::Win | GameStatus::GameOver => finished = true,
+            GameStatus::Playing if finished => return,
+            _ => {}
+        }
+    }
+    panic!("demo never started another game");
+}
@@ -0,0 +1,166 @@
+//! Sound effects played note by note, one sequencer tick at a time.
+
+use minesweeper_core::game::GameEvent;
+use minesweeper_core::sound::*;
+
+/// Frequency on the buzzer after each of `ticks` ticks.
+fn run(sequencer: &mut Sequencer, output: &mut u16, ticks: usize) -> Vec<u16> {
+    (0..ticks)
+        .map(|_| {
+            if let Some(freq) = sequencer.tick() {
+                *output = freq;
+            }
+            *output
+        })
+        .collect()
+}
+
+/// Each note of the effect repeated for as many ticks as it lasts.
+fn expand(effect: Effect) -> Vec<u16> {
+    effect
+        .notes()
+        .iter()
+        .flat_map(|note| std::iter::repeat_n(note.freq, note.ticks as usize))
+        .collect()
+}
+
+const ALL: [Effect; 5] = [
+    Effect::Reveal,
+    Effect::Flag,
+    Effect::Chord,
+    Effect::Explosion,
+    Effect::Win,
+];
+
+#[test]
+fn silent_until_an_effect_starts() {
+    let mut sequencer = Sequencer::default();
+    for _ in 0..10 {
+        assert_eq!(sequencer.tick(), None);
+    }
+    sequencer.play(Effect::Reveal);
+    assert_eq!(sequencer.tick(), Some(1760), "starts on the next tick");
+}
+
+#[test]
+fn notes_last_their_ticks() {
+    for effect in ALL {
+        let mut sequencer = Sequencer::new();
+        let mut output = 0;
+        sequencer.play(effect);
+        let notes = expand(effect);
+        assert_eq!(run(&mut sequencer, &mut output, notes.len()), notes);
+    }
+}
+
+#[test]
+fn tick_reports_only_changes() {
+    let mut sequencer = Sequencer::new();
+    sequencer.play(Effect::Flag);
+    let changes: Vec<_> = (0..8).map(|_| sequencer.tick()).collect();
+    assert_eq!(
+        changes,
+        [Some(988), None, None, Some(1319), None, None, Some(0), None]
+    );
+}
+
+#[test]
+fn falls_silent_at_end_of_sequence() {
+    for effect in ALL {
+        let mut sequencer = Sequencer::new();
+        let mut output = 0;
+        sequencer.play(effect);
+        run(&mut sequencer, &mut output, expand(effect).len());
+        assert_eq!(sequencer.tick(), Some(0), "{:?} left the buzzer on", effect);
+        for _ in 0..10 {
+            assert_eq!(sequencer.tick(), None);
+        }
+    }
+}
+
+#[test]
+fn effects_queue_in_order() {
+    let mut sequencer = Sequencer::new();
+    let mut output = 0;
+    sequencer.play(Effect::Reveal);
+    sequencer.play(Effect::Flag);
+    sequencer.play(Effect::Chord);
+    let played = run(&mut sequencer, &mut output, 20);
+    let expected = [
+        expand(Effect::Reveal),
+        expand(Effect::Flag),
+        expand(Effect::Chord),
+    ]
+    .concat();
+    assert_eq!(played[..expected.len()], expected[..]);
+    assert!(played[expected.len()..].iter().all(|&freq| freq == 0));
+}
+
+#[test]
+fn full_queue_drops_effects() {
+    let mut sequencer = Sequencer::new();
+    let mut output = 0;
+    for _ in 0..10 {
+        sequencer.play(Effect::Reveal);
+    }
+    let played = run(&mut sequencer, &mut output, 40);
+    // Back to back reveals never change the output between them
+    let reveal = expand(Effect::Reveal).len();
+    assert!(played[..4 * reveal].iter().all(|&freq| freq == 1760));
+    assert!(played[4 * reveal..].iter().all(|&freq| freq == 0));
+}
+
+#[test]
+fn game_end_preempts_what_is_playing() {
+    for end in [Effect::Explosion, Effect::Win] {
+        let mut sequencer = Sequencer::new();
+        let mut output = 0;
+        sequencer.play(Effect::Chord);
+        sequencer.play(Effect::Flag);
+        run(&mut sequencer, &mut output, 3);
+        sequencer.play(end);
+        let notes = expand(end);
+        let played = run(&mut sequencer, &mut output, notes.len() + 10);
+        assert_eq!(played[..notes.len()], notes[..], "queued flag was kept");
+        assert!(played[notes.len()..].iter().all(|&freq| freq == 0));
+    }
+
+    // Anything else waits its turn
+    let mut sequencer = Sequencer::new();
+    let mut output = 0;
+    sequencer.play(Effect::Explosion);
+    run(&mut sequencer, &mut output, 2);
+    sequencer.play(Effect::Reveal);
+    let played = run(&mut sequencer, &mut output, 2);
+    assert_eq!(played, [440, 440]);
+}
+
+#[test]
+fn stop_silences_and_clears_queue() {
+    let mut sequencer = Sequencer::new();
+    let mut output = 0;
+    sequencer.play(Effect::Win);
+    sequencer.play(Effect::Reveal);
+    run(&mut sequencer, &mut output, 5);
+    assert_ne!(output, 0);
+
+    sequencer.stop();
+    assert_eq!(sequencer.tick(), Some(0));
+    for _ in 0..100 {
+        assert_eq!(sequencer.tick(), None);
+    }
+}
+
+#[test]
+fn game_events_pick_effects() {
+    let events = [
+        GameEvent::Reveal,
+        GameEvent::Flag,
+        GameEvent::Chord,
+        GameEvent::Explosion,
+        GameEvent::Win,
+    ];
+    for (event, effect) in events.iter().zip(ALL) {
+        assert_eq!(Effect::from(*event), effect);
+    }
+}
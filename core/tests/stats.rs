@@ -0,0 +1,342 @@
+//! Game statistics: 3BV and clicks of a single game, lifetime counters per difficulty.
+
+use klaptik::Point;
+use minesweeper_core::board::*;
+use minesweeper_core::extension::*;
+use minesweeper_core::game::*;
+use minesweeper_core::input::*;
+use minesweeper_core::menu::*;
+use minesweeper_core::settings::*;
+use minesweeper_core::stats::*;
+
+mod common;
+
+use common::move_to;
+
+fn find_closed(game: &Minesweeper) -> Point {
+    let board = game.board();
+    (0..board.height())
+        .flat_map(|y| (0..board.width()).map(move |x| Point::new(x as i32, y as i32)))
+        .find(|&pos| board.tile_at(pos).status() == TileStatus::Closed)
+        .unwrap()
+}
+
+fn new_game() -> Minesweeper {
+    let mut game = Minesweeper::new(Difficulty::Easy.config());
+    game.seed_random(1337);
+    game
+}
+
+#[test]
+fn three_bv_is_set_when_board_is_dealt() {
+    let mut game = new_game();
+    assert_eq!(game.three_bv(), 0);
+
+    game.button_click(GameButton::A);
+    assert!(game.three_bv() > 0);
+    assert_eq!(game.three_bv() as usize, game.board().three_bv());
+}
+
+#[test]
+fn clicks_count_only_game_buttons_in_play() {
+    let mut game = new_game();
+    game.button_click(GameButton::B);
+    game.button_click(GameButton::Chord);
+    assert_eq!(game.clicks(), 0, "clicks before the board is dealt");
+
+    game.button_click(GameButton::A);
+    assert_eq!(game.clicks(), 1);
+
+    game.button_click(GameButton::DPad(Dir::Left));
+    game.button_click(GameButton::DPad(Dir::Up));
+    assert_eq!(game.clicks(), 1, "cursor moves are not clicks");
+
+    game.button_click(GameButton::Chord);
+    game.button_click(GameButton::B);
+    game.button_click(GameButton::B);
+    game.button_click(GameButton::A);
+    assert_eq!(game.clicks(), 5, "wasted clicks count too");
+}
+
+#[test]
+fn rate_and_efficiency_follow_clicks_and_time() {
+    let mut game = new_game();
+    game.button_click(GameButton::A);
+    let mut clicks = 1;
+    while game.status() == GameStatus::Playing {
+        let pos = find_closed(&game);
+        move_to(&mut game, pos);
+        if game.board().tile_at(pos).content() == TileContent::Bomb {
+            // Every mine has to be flagged to win
+            game.button_click(GameButton::B);
+        } else {
+            game.button_click(GameButton::A);
+        }
+        clicks += 1;
+        for _ in 0..Minesweeper::TICKS_PER_SECOND {
+            game.tick();
+        }
+    }
+
+    assert!(game.status() == GameStatus::Win);
+    assert_eq!(game.clicks(), clicks);
+    let three_bv = game.three_bv() as u32;
+    assert_eq!(game.efficiency(), three_bv * 100 / clicks as u32);
+    let secs = game.elapsed_secs() as u32;
+    assert_eq!(game.three_bv_rate(), three_bv * 100 / secs);
+}
+
+/// Presses and releases nunchuk buttons and plays the actions like the firmware does.
+fn press(game: &mut Minesweeper, mapper: &mut InputMapper, settings: &Settings, z: bool, c: bool) {
+    for (button_z, button_c) in [(z, c), (false, false)] {
+        let reading = Reading::Nunchuk(NunchukReading {
+            joystick_x: 127,
+            joystick_y: 127,
+            button_z,
+            button_c,
+            ..NunchukReading::default()
+        });
+        let mut actions = Vec::new();
+        mapper.map(settings, &reading, |action| actions.push(action));
+        for action in actions {
+            let button = match resolve_chord(settings, action, game) {
+                Action::Open => GameButton::A,
+                Action::Flag => GameButton::B,
+                Action::Chord => GameButton::Chord,
+                _ => continue,
+            };
+            game.button_click(button);
+        }
+    }
+}
+
+#[test]
+fn chord_counts_one_click_with_every_chord_button() {
+    let mut boards = Vec::new();
+    for chord in [ChordButton::Both, ChordButton::Open, ChordButton::Flag] {
+        let mut settings = Settings::default();
+        settings.action_map.chord = chord;
+        let mut mapper = InputMapper::default();
+        let mut game = new_game();
+        move_to(&mut game, Point::new(8, 3));
+        press(&mut game, &mut mapper, &settings, true, false);
+
+        // A hint with a closed tile left to open around it
+        let board = game.board();
+        let hint = (0..board.height())
+            .flat_map(|y| (0..board.width()).map(move |x| Point::new(x as i32, y as i32)))
+            .find(|&pos| {
+                let tile = board.tile_at(pos);
+                tile.status() == TileStatus::Opened
+                    && tile.content() != TileContent::Hint(0)
+                    && board.neighbors(pos).any(|pos| {
+                        let tile = board.tile_at(pos);
+                        tile.status() == TileStatus::Closed && tile.content() != TileContent::Bomb
+                    })
+            })
+            .unwrap();
+        let mines: Vec<Point> = board
+            .neighbors(hint)
+            .filter(|&pos| board.tile_at(pos).content() == TileContent::Bomb)
+            .collect();
+        for &mine in &mines {
+            move_to(&mut game, mine);
+            press(&mut game, &mut mapper, &settings, false, true);
+        }
+
+        move_to(&mut game, hint);
+        let opened = game
+            .board()
+            .tiles()
+            .iter()
+            .filter(|tile| tile.status() == TileStatus::Opened)
+            .count();
+        match chord {
+            ChordButton::Both => press(&mut game, &mut mapper, &settings, true, true),
+            ChordButton::Open => press(&mut game, &mut mapper, &settings, true, false),
+            ChordButton::Flag => press(&mut game, &mut mapper, &settings, false, true),
+        }
+        let chorded = game
+            .board()
+            .tiles()
+            .iter()
+            .filter(|tile| tile.status() == TileStatus::Opened)
+            .count();
+        assert!(chorded > opened, "chord opened nothing");
+
+        let clicks = 1 + mines.len() as u16 + 1;
+        assert_eq!(game.clicks(), clicks, "open, flags and one chord");
+        let three_bv = game.three_bv() as u32;
+        assert_eq!(game.efficiency(), three_bv * 100 / clicks as u32);
+        boards.push(
+            game.board()
+                .tiles()
+                .iter()
+                .map(|tile| tile.status())
+                .collect::<Vec<_>>(),
+        );
+    }
+    assert!(boards.windows(2).all(|pair| pair[0] == pair[1]));
+}
+
+#[test]
+fn restart_resets_stats() {
+    let mut game = new_game();
+    game.button_click(GameButton::A);
+    game.button_click(GameButton::B);
+    assert!(game.clicks() > 0);
+
+    game.restart(Difficulty::Normal.config());
+    game.button_click(GameButton::A);
+    assert_eq!(game.clicks(), 1);
+    assert_eq!(game.three_bv() as usize, game.board().three_bv());
+}
+
+fn play(stats: &mut Stats, difficulty: Difficulty, end: GameStatus, secs: u16) {
+    stats.record(difficulty, GameStatus::Bootstrap, GameStatus::Playing, 0);
+    stats.record(difficulty, GameStatus::Playing, end, secs);
+}
+
+#[test]
+fn lifetime_counters_follow_status_changes() {
+    let mut stats = Stats::new();
+    play(&mut stats, Difficulty::Hard, GameStatus::Win, 100);
+    play(&mut stats, Difficulty::Hard, GameStatus::Win, 200);
+    play(&mut stats, Difficulty::Hard, GameStatus::GameOver, 50);
+    play(&mut stats, Difficulty::Hard, GameStatus::Win, 300);
+    // Abandoned for a new game
+    stats.record(
+        Difficulty::Hard,
+        GameStatus::Bootstrap,
+        GameStatus::Playing,
+        0,
+    );
+    stats.record(
+        Difficulty::Hard,
+        GameStatus::Playing,
+        GameStatus::Bootstrap,
+        40,
+    );
+
+    let hard = stats.level(Difficulty::Hard);
+    assert_eq!(
+        *hard,
+        LevelStats {
+            played: 5,
+            won: 3,
+            lost: 1,
+            streak: 1,
+            best_streak: 2,
+            play_secs: 650,
+            win_secs: 600,
+        }
+    );
+    assert_eq!(hard.average_win_secs(), Some(200));
+    assert_eq!(*stats.level(Difficulty::Easy), LevelStats::default());
+    assert_eq!(stats.level(Difficulty::Easy).average_win_secs(), None);
+}
+
+#[test]
+fn game_won_on_first_click_is_played() {
+    let mut stats = Stats::new();
+    stats.record(Difficulty::Easy, GameStatus::Bootstrap, GameStatus::Win, 0);
+    // New board dealt right after a finished game
+    stats.record(Difficulty::Easy, GameStatus::Win, GameStatus::Playing, 0);
+
+    let easy = stats.level(Difficulty::Easy);
+    assert_eq!((easy.played, easy.won, easy.streak), (2, 1, 1));
+}
+
+#[test]
+fn stats_record_round_trips() {
+    let mut stats = Stats::new();
+    play(&mut stats, Difficulty::Easy, GameStatus::Win, 42);
+    play(&mut stats, Difficulty::Normal, GameStatus::GameOver, 7);
+    play(&mut stats, Difficulty::Hard, GameStatus::Win, u16::MAX);
+
+    let mut record = stats.encode();
+    assert_eq!(Stats::decode(&record), Some(stats));
+
+    record[5] ^= 1;
+    assert_eq!(Stats::decode(&record), None, "corrupted record accepted");
+    assert_eq!(
+        Stats::decode(&[0xff; Stats::RECORD_SIZE]),
+        None,
+        "erased flash accepted"
+    );
+}
+
+fn open_stats(menu: &mut Menu, settings: &mut Settings) {
+    let items = Screen::Title.items();
+    let stats = items.iter().position(|&item| item == MenuItem::Stats);
+    for _ in 0..stats.unwrap() {
+        menu.handle(Action::Move(Dir::Down), settings);
+    }
+    menu.handle(Action::Open, settings);
+    assert!(menu.screen() == Screen::Stats);
+}
+
+#[test]
+fn reset_needs_confirmation() {
+    let mut menu = Menu::new();
+    let mut settings = Settings::default();
+    open_stats(&mut menu, &mut settings);
+
+    for _ in 0..Screen::Stats.items().len() {
+        menu.handle(Action::Move(Dir::Down), &mut settings);
+    }
+    assert!(menu.handle(Action::Open, &mut settings) == MenuEvent::None);
+    assert!(menu.confirming_reset());
+
+    // Moving away cancels
+    menu.handle(Action::Move(Dir::Up), &mut settings);
+    assert!(!menu.confirming_reset());
+    menu.handle(Action::Move(Dir::Down), &mut settings);
+    menu.handle(Action::Open, &mut settings);
+    assert!(menu.handle(Action::Open, &mut settings) == MenuEvent::ResetStats);
+}
+
+#[test]
+fn stats_screen_switches_level() {
+    let mut menu = Menu::new();
+    let mut settings = Settings {
+        difficulty: Difficulty::Hard,
+        ..Settings::default()
+    };
+    open_stats(&mut menu, &mut settings);
+    assert!(menu.stats_level() == Difficulty::Hard);
+
+    menu.handle(Action::Move(Dir::Right), &mut settings);
+    assert!(menu.stats_level() == Difficulty::Easy);
+    menu.handle(Action::Move(Dir::Left), &mut settings);
+    menu.handle(Action::Move(Dir::Left), &mut settings);
+    assert!(menu.stats_level() == Difficulty::Normal);
+}
+
+#[test]
+fn only_preset_boards_are_ranked() {
+    let mut menu = Menu::new();
+    let mut stats = Stats::new();
+    let mut game = new_game();
+    menu.start_game(Difficulty::Easy);
+
+    // The remote kept the Easy board but dealt it with a single mine
+    game.restart(GameConfig {
+        bombs: 1,
+        ..game.config()
+    });
+    menu.play(&mut game, &mut stats, |game| {
+        game.button_click(GameButton::A)
+    });
+    assert!(game.status() != GameStatus::Bootstrap);
+    assert_eq!(stats, Stats::new());
+
+    // A preset board counts for its own level, whatever the menu last started
+    game.restart(Difficulty::Normal.config());
+    menu.play(&mut game, &mut stats, |game| {
+        game.button_click(GameButton::A)
+    });
+    assert!(menu.game_difficulty() == Difficulty::Normal);
+    assert_eq!(stats.level(Difficulty::Normal).played, 1);
+    assert_eq!(stats.level(Difficulty::Easy).played, 0);
+}
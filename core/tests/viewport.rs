@@ -0,0 +1,90 @@
+//! Viewport scrolling: the cursor margin and the HUD's `^ < v >` indicators.
+
+use klaptik::Point;
+use minesweeper_core::viewport::*;
+
+/// Indicators in HUD order: above, left, below, right.
+fn more(viewport: &Viewport) -> [bool; 4] {
+    [
+        viewport.more_above(),
+        viewport.more_left(),
+        viewport.more_below(),
+        viewport.more_right(),
+    ]
+}
+
+/// Walks the cursor along a one row board, returns the origin after each step.
+fn walk(cols: usize, width: usize, path: impl Iterator<Item = i32>) -> Vec<i32> {
+    let mut viewport = Viewport::new(cols, 1);
+    path.map(|x| {
+        viewport.follow(Point::new(x, 0), width, 1);
+        viewport.origin().x
+    })
+    .collect()
+}
+
+#[test]
+fn cursor_scrolls_one_tile_from_the_edge() {
+    // Five columns of twelve: the view moves once the cursor enters the outer column
+    assert_eq!(walk(5, 12, 0..12), [0, 0, 0, 0, 1, 2, 3, 4, 5, 6, 7, 7]);
+    // Coming back it stays put until the cursor reaches the left margin
+    let mut viewport = Viewport::new(5, 1);
+    viewport.follow(Point::new(11, 0), 12, 1);
+    let origins: Vec<i32> = (0..12)
+        .rev()
+        .map(|x| {
+            viewport.follow(Point::new(x, 0), 12, 1);
+            viewport.origin().x
+        })
+        .collect();
+    assert_eq!(origins, [7, 7, 7, 7, 6, 5, 4, 3, 2, 1, 0, 0]);
+}
+
+#[test]
+fn narrow_windows_have_no_margin() {
+    assert_eq!(walk(2, 5, 0..5), [0, 0, 1, 2, 3]);
+    assert_eq!(walk(1, 5, 0..5), [0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn indicators_follow_every_board_edge() {
+    let corners = [
+        (Point::new(0, 0), [false, false, true, true]),
+        (Point::new(11, 0), [false, true, true, false]),
+        (Point::new(0, 7), [true, false, false, true]),
+        (Point::new(11, 7), [true, true, false, false]),
+        (Point::new(6, 4), [true, true, true, true]),
+    ];
+    for (cursor, expected) in corners {
+        let mut viewport = Viewport::new(5, 3);
+        viewport.follow(cursor, 12, 8);
+        assert_eq!(more(&viewport), expected, "cursor at {:?}", cursor);
+    }
+
+    // Each edge on its own, the view is on the far side of the others
+    let edges = [
+        (Point::new(6, 0), [false, true, true, true]),
+        (Point::new(0, 4), [true, false, true, true]),
+        (Point::new(6, 7), [true, true, false, true]),
+        (Point::new(11, 4), [true, true, true, false]),
+    ];
+    for (cursor, expected) in edges {
+        let mut viewport = Viewport::new(5, 3);
+        viewport.follow(cursor, 12, 8);
+        assert_eq!(more(&viewport), expected, "cursor at {:?}", cursor);
+    }
+}
+
+#[test]
+fn boards_smaller_than_the_screen_never_scroll() {
+    for (width, height) in [(5, 3), (8, 4), (1, 1)] {
+        let mut viewport = Viewport::new(8, 4);
+        for y in 0..height as i32 {
+            for x in 0..width as i32 {
+                viewport.follow(Point::new(x, y), width, height);
+                assert_eq!(viewport.origin(), Point::new(0, 0));
+                assert_eq!(more(&viewport), [false; 4]);
+            }
+        }
+    }
+}
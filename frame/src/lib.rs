@@ -0,0 +1,170 @@
+//! In-memory stand-in for the ST7567, so UI frames can be captured and reviewed on the host.
+
+use std::fmt;
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+
+use klaptik::*;
+use minesweeper_core::ui::{GameUI, UiState};
+
+/// 128x64 monochrome frame in the display's native layout: one byte per
+/// column of each 8 pixel tall page, least significant bit on top.
+#[derive(Clone, PartialEq, Eq)]
+pub struct FrameBuffer {
+    pages: [[u8; FrameBuffer::WIDTH]; FrameBuffer::PAGES],
+}
+
+impl FrameBuffer {
+    pub const WIDTH: usize = 128;
+    pub const HEIGHT: usize = 64;
+    const PAGES: usize = Self::HEIGHT / 8;
+
+    pub fn new() -> Self {
+        Self {
+            pages: [[0; Self::WIDTH]; Self::PAGES],
+        }
+    }
+
+    /// Renders the whole UI for `state` into a fresh frame.
+    pub fn capture(state: &UiState) -> Self {
+        let mut ui = GameUI::new();
+        ui.update(state);
+        let mut frame = Self::new();
+        ui.render(&mut frame);
+        frame
+    }
+
+    pub fn clear(&mut self) {
+        self.pages = [[0; Self::WIDTH]; Self::PAGES];
+    }
+
+    /// Whether the pixel at `x`, `y` is lit; out of bounds pixels are dark.
+    pub fn pixel(&self, x: usize, y: usize) -> bool {
+        x < Self::WIDTH && y < Self::HEIGHT && self.pages[y / 8][x] & (1 << (y % 8)) != 0
+    }
+
+    pub fn set_pixel(&mut self, x: usize, y: usize, on: bool) {
+        if x >= Self::WIDTH || y >= Self::HEIGHT {
+            return;
+        }
+        let mask = 1 << (y % 8);
+        if on {
+            self.pages[y / 8][x] |= mask;
+        } else {
+            self.pages[y / 8][x] &= !mask;
+        }
+    }
+
+    /// Binary (P4) PBM image, lit pixels are black like on the panel.
+    pub fn to_pbm(&self) -> Vec<u8> {
+        let mut pbm = format!("P4\n{} {}\n", Self::WIDTH, Self::HEIGHT).into_bytes();
+        pbm.extend(self.rows(true));
+        pbm
+    }
+
+    /// Parses a binary PBM image of the display size, as written by `to_pbm`.
+    pub fn from_pbm(pbm: &[u8]) -> Option<Self> {
+        let mut fields = pbm.splitn(4, |byte| byte.is_ascii_whitespace());
+        let magic = fields.next()?;
+        let width = fields.next()?;
+        let height = fields.next()?;
+        let data = fields.next()?;
+        let expected = format!("{} {}", Self::WIDTH, Self::HEIGHT);
+        if magic != b"P4" || [width, b" ", height].concat() != expected.as_bytes() {
+            return None;
+        }
+        if data.len() != Self::WIDTH / 8 * Self::HEIGHT {
+            return None;
+        }
+
+        let mut frame = Self::new();
+        for (idx, byte) in data.iter().enumerate() {
+            let y = idx / (Self::WIDTH / 8);
+            let x = idx % (Self::WIDTH / 8) * 8;
+            for bit in 0..8 {
+                frame.set_pixel(x + bit, y, byte & (0x80 >> bit) != 0);
+            }
+        }
+        Some(frame)
+    }
+
+    pub fn write_png(&self, out: impl Write) -> io::Result<()> {
+        let mut encoder = png::Encoder::new(out, Self::WIDTH as u32, Self::HEIGHT as u32);
+        encoder.set_color(png::ColorType::Grayscale);
+        encoder.set_depth(png::BitDepth::One);
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.rows(false))?;
+        writer.finish()?;
+        Ok(())
+    }
+
+    /// Saves the frame as PNG or PBM, picked by the file extension.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("pbm") => fs::write(path, self.to_pbm()),
+            Some("png") => self.write_png(io::BufWriter::new(fs::File::create(path)?)),
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "frames can only be saved as .png or .pbm",
+            )),
+        }
+    }
+
+    /// Packs pixels into rows, most significant bit first, with `lit` as the
+    /// value of a lit pixel.
+    fn rows(&self, lit: bool) -> Vec<u8> {
+        let mut rows = Vec::with_capacity(Self::WIDTH / 8 * Self::HEIGHT);
+        for y in 0..Self::HEIGHT {
+            for chunk in 0..Self::WIDTH / 8 {
+                let mut byte = 0;
+                for bit in 0..8 {
+                    if self.pixel(chunk * 8 + bit, y) == lit {
+                        byte |= 0x80 >> bit;
+                    }
+                }
+                rows.push(byte);
+            }
+        }
+        rows
+    }
+}
+
+impl Default for FrameBuffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Draws the frame as text, one character per pixel.
+impl fmt::Debug for FrameBuffer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for y in 0..Self::HEIGHT {
+            for x in 0..Self::WIDTH {
+                f.write_str(if self.pixel(x, y) { "#" } else { "." })?;
+            }
+            f.write_str("\n")?;
+        }
+        Ok(())
+    }
+}
+
+impl Canvas for FrameBuffer {
+    fn draw(&mut self, bounds: Rectangle, bitmap: &[u8]) {
+        let width = bounds.size.width as usize;
+        if width == 0 {
+            return;
+        }
+        for (idx, column) in bitmap.iter().enumerate() {
+            let x = bounds.origin.x + (idx % width) as i32;
+            let top = bounds.origin.y + (idx / width) as i32 * 8;
+            for bit in 0..8 {
+                let y = top + bit;
+                if x >= 0 && y >= 0 {
+                    self.set_pixel(x as usize, y as usize, column & (1 << bit) != 0);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,136 @@
+//! Counts what a redraw sends to the display after small changes.
+
+use klaptik::*;
+use minesweeper_core::board::*;
+use minesweeper_core::game::*;
+use minesweeper_core::menu::Menu;
+use minesweeper_core::settings::*;
+use minesweeper_core::stats::Stats;
+use minesweeper_core::ui::*;
+use minesweeper_frame::FrameBuffer;
+
+#[path = "../../core/tests/common/mod.rs"]
+mod common;
+
+use common::move_to;
+
+/// Display that records every draw on top of the frame it composes.
+#[derive(Default)]
+struct Wire {
+    frame: FrameBuffer,
+    draws: Vec<Span>,
+    bytes: usize,
+}
+
+/// Left, top, width and height of a draw.
+type Span = (i32, i32, usize, usize);
+
+impl Canvas for Wire {
+    fn draw(&mut self, bounds: Rectangle, bitmap: &[u8]) {
+        self.frame.draw(bounds, bitmap);
+        let Rectangle { origin, size } = bounds;
+        self.draws.push((
+            origin.x,
+            origin.y,
+            size.width as usize,
+            size.height as usize,
+        ));
+        self.bytes += bitmap.len();
+    }
+}
+
+struct Device {
+    ui: GameUI,
+    wire: Wire,
+    game: Minesweeper,
+    menu: Menu,
+}
+
+impl Device {
+    fn new() -> Self {
+        let mut game = Minesweeper::new(Difficulty::Easy.config());
+        game.seed_random(1337);
+        move_to(&mut game, Point::new(8, 3));
+        game.button_click(GameButton::A);
+        let mut menu = Menu::new();
+        menu.enter_game();
+        Self {
+            ui: GameUI::new(),
+            wire: Wire::default(),
+            game,
+            menu,
+        }
+    }
+
+    /// Redraws like the render task does, returns the draws and bytes sent.
+    fn render(&mut self) -> (Vec<Span>, usize) {
+        let settings = Settings::default();
+        let stats = Stats::default();
+        let state = UiState {
+            game: &self.game,
+            menu: &self.menu,
+            settings: &settings,
+            stats: &stats,
+        };
+        self.ui.update(&state);
+        self.wire.draws.clear();
+        self.wire.bytes = 0;
+        self.ui.render(&mut self.wire);
+        assert_eq!(
+            self.wire.frame,
+            FrameBuffer::capture(&state),
+            "stale pixels"
+        );
+        (self.wire.draws.clone(), self.wire.bytes)
+    }
+}
+
+/// Display area of an Easy board tile, the board fills the screen below the HUD.
+fn tile_span(pos: Point) -> Span {
+    (pos.x * 8, 16 + pos.y * 8, 8, 8)
+}
+
+#[test]
+fn unchanged_frame_sends_nothing() {
+    let mut device = Device::new();
+    let (_, full) = device.render();
+    assert!(full >= 1024, "first frame covers the screen");
+    assert_eq!(device.render(), (vec![], 0));
+}
+
+#[test]
+fn cursor_move_sends_its_two_tiles() {
+    let mut device = Device::new();
+    device.render();
+    let from = device.game.board().cursor();
+    device.game.button_click(GameButton::DPad(Dir::Right));
+
+    let (draws, bytes) = device.render();
+    let (x, y, _, _) = tile_span(from);
+    assert_eq!(draws, [(x, y, 16, 8)]);
+    assert_eq!(bytes, 16);
+}
+
+#[test]
+fn one_tile_sends_its_page_and_columns_and_the_counter() {
+    let mut device = Device::new();
+    let board = device.game.board();
+    let closed = (0..board.height() as i32)
+        .flat_map(|y| (0..board.width() as i32).map(move |x| Point::new(x, y)))
+        .find(|&pos| board.tile_at(pos).status() == TileStatus::Closed)
+        .unwrap();
+    move_to(&mut device.game, closed);
+    let (_, full) = device.render();
+
+    device.game.button_click(GameButton::B);
+    let (draws, bytes) = device.render();
+    let (tile, hud): (Vec<_>, Vec<_>) = draws.into_iter().partition(|&(_, y, _, _)| y >= 16);
+    assert_eq!(tile, [tile_span(closed)]);
+    assert!(!hud.is_empty(), "mine counter not redrawn");
+    assert!(
+        hud.iter().all(|&(_, y, _, height)| y == 0 && height == 8),
+        "{:?} outside the HUD line",
+        hud
+    );
+    assert!(bytes <= 8 + 3 * 6, "{} of {} bytes sent", bytes, full);
+}
@@ -0,0 +1,354 @@
+//! Compares rendered screens against the golden frames in `tests/golden`.
+//!
+//! A missing golden fails the test. Run with `UPDATE_GOLDEN=1` to record new
+//! goldens or to rewrite every golden after intentional sprite or layout
+//! changes, then review and commit them.
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+use klaptik::{Point, Widget};
+use minesweeper_core::board::*;
+use minesweeper_core::game::*;
+use minesweeper_core::input::Action;
+use minesweeper_core::menu::Menu;
+use minesweeper_core::settings::*;
+use minesweeper_core::stats::Stats;
+use minesweeper_core::ui::*;
+use minesweeper_frame::FrameBuffer;
+
+#[path = "../../core/tests/common/mod.rs"]
+mod common;
+
+use common::move_to;
+
+const SEED: u32 = 1337;
+
+fn golden_path(name: &str, ext: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("golden")
+        .join(format!("{}.{}", name, ext))
+}
+
+fn assert_snapshot(name: &str, frame: &FrameBuffer) {
+    let path = golden_path(name, "pbm");
+    if env::var_os("UPDATE_GOLDEN").is_some() {
+        fs::write(&path, frame.to_pbm()).unwrap();
+        return;
+    }
+
+    let golden = match fs::read(&path) {
+        Ok(pbm) => FrameBuffer::from_pbm(&pbm)
+            .unwrap_or_else(|| panic!("{} is not a valid golden frame", path.display())),
+        Err(_) => panic!(
+            "missing golden {}, record it with UPDATE_GOLDEN=1",
+            path.display()
+        ),
+    };
+
+    if golden != *frame {
+        let actual = golden_path(name, "actual.png");
+        frame.save(&actual).unwrap();
+        panic!(
+            "{} differs from its golden, actual frame saved to {}\n{:?}",
+            name,
+            actual.display(),
+            frame
+        );
+    }
+}
+
+fn new_game(difficulty: Difficulty) -> Minesweeper {
+    let mut game = Minesweeper::new(difficulty.config());
+    game.seed_random(SEED);
+    game
+}
+
+fn capture_game(game: &Minesweeper) -> FrameBuffer {
+    let mut menu = Menu::new();
+    menu.enter_game();
+    capture(game, &menu, &Settings::default(), &Stats::default())
+}
+
+fn capture(game: &Minesweeper, menu: &Menu, settings: &Settings, stats: &Stats) -> FrameBuffer {
+    FrameBuffer::capture(&UiState {
+        game,
+        menu,
+        settings,
+        stats,
+    })
+}
+
+fn find_tile(game: &Minesweeper, matches: impl Fn(Tile) -> bool) -> Point {
+    let board = game.board();
+    (0..board.height())
+        .flat_map(|y| (0..board.width()).map(move |x| Point::new(x as i32, y as i32)))
+        .find(|&pos| matches(board.tile_at(pos)))
+        .expect("no tile matches")
+}
+
+/// Easy game with the first click in the middle of the board.
+fn opened_game() -> Minesweeper {
+    let mut game = new_game(Difficulty::Easy);
+    move_to(&mut game, Point::new(8, 3));
+    game.button_click(GameButton::A);
+    game
+}
+
+/// Opens the menu item at each index in turn, starting from the title screen.
+fn open_items(menu: &mut Menu, settings: &mut Settings, items: &[usize]) {
+    for &item in items {
+        for _ in 0..item {
+            menu.handle(Action::Move(Dir::Down), settings);
+        }
+        menu.handle(Action::Open, settings);
+    }
+}
+
+fn capture_menu(items: &[usize]) -> FrameBuffer {
+    let mut menu = Menu::new();
+    let mut settings = Settings::default();
+    open_items(&mut menu, &mut settings, items);
+    capture(
+        &new_game(Difficulty::Easy),
+        &menu,
+        &settings,
+        &Stats::default(),
+    )
+}
+
+#[test]
+fn title_menu() {
+    assert_snapshot("title_menu", &capture_menu(&[]));
+}
+
+#[test]
+fn difficulty_menu() {
+    assert_snapshot("difficulty_menu", &capture_menu(&[2]));
+}
+
+#[test]
+fn settings_menu() {
+    assert_snapshot("settings_menu", &capture_menu(&[3]));
+}
+
+#[test]
+fn scores_menu() {
+    let mut menu = Menu::new();
+    let mut settings = Settings::default();
+    menu.start_game(Difficulty::Normal);
+    menu.record_win(83);
+    menu.open();
+    open_items(&mut menu, &mut settings, &[4]);
+    let frame = capture(
+        &new_game(Difficulty::Easy),
+        &menu,
+        &settings,
+        &Stats::default(),
+    );
+    assert_snapshot("scores_menu", &frame);
+}
+
+#[test]
+fn stats_menu() {
+    let mut stats = Stats::default();
+    let games = [
+        (GameStatus::Win, 83),
+        (GameStatus::Win, 61),
+        (GameStatus::GameOver, 12),
+        (GameStatus::Win, 6000),
+    ];
+    for (end, secs) in games {
+        stats.record(
+            Difficulty::Normal,
+            GameStatus::Bootstrap,
+            GameStatus::Playing,
+            0,
+        );
+        stats.record(Difficulty::Normal, GameStatus::Playing, end, secs);
+    }
+
+    let mut menu = Menu::new();
+    let mut settings = Settings::default();
+    open_items(&mut menu, &mut settings, &[5]);
+    menu.handle(Action::Move(Dir::Right), &mut settings);
+    let frame = capture(&new_game(Difficulty::Easy), &menu, &settings, &stats);
+    assert_snapshot("stats_menu", &frame);
+
+    for _ in 0..8 {
+        menu.handle(Action::Move(Dir::Down), &mut settings);
+    }
+    menu.handle(Action::Open, &mut settings);
+    let frame = capture(&new_game(Difficulty::Easy), &menu, &settings, &stats);
+    assert_snapshot("stats_menu_reset", &frame);
+}
+
+#[test]
+fn challenge_menu() {
+    let mut menu = Menu::new();
+    let mut settings = Settings::default();
+    let game = opened_game();
+    open_items(&mut menu, &mut settings, &[6]);
+    let frame = capture(&game, &menu, &settings, &Stats::default());
+    assert_snapshot("challenge_menu", &frame);
+
+    open_items(&mut menu, &mut settings, &[5]);
+    for _ in 0..3 {
+        menu.handle(Action::Move(Dir::Up), &mut settings);
+        menu.handle(Action::Move(Dir::Right), &mut settings);
+    }
+    menu.handle(Action::Open, &mut settings);
+    let frame = capture(&game, &menu, &settings, &Stats::default());
+    assert_snapshot("code_entry", &frame);
+}
+
+#[test]
+fn about_menu() {
+    assert_snapshot("about_menu", &capture_menu(&[7]));
+}
+
+#[test]
+fn fresh_board() {
+    assert_snapshot("fresh_board", &capture_game(&new_game(Difficulty::Easy)));
+}
+
+#[test]
+fn cursor_on_closed_tile() {
+    let mut game = opened_game();
+    let pos = find_tile(&game, |tile| tile.status() == TileStatus::Closed);
+    move_to(&mut game, pos);
+    assert_snapshot("cursor_on_closed_tile", &capture_game(&game));
+}
+
+#[test]
+fn cursor_on_flag() {
+    let mut game = opened_game();
+    let pos = find_tile(&game, |tile| tile.status() == TileStatus::Closed);
+    move_to(&mut game, pos);
+    game.button_click(GameButton::B);
+    assert_snapshot("cursor_on_flag", &capture_game(&game));
+}
+
+#[test]
+fn cursor_on_empty_tile() {
+    let mut game = opened_game();
+    let pos = find_tile(&game, |tile| tile.content() == TileContent::Hint(0));
+    move_to(&mut game, pos);
+    game.button_click(GameButton::A);
+    assert_snapshot("cursor_on_empty_tile", &capture_game(&game));
+}
+
+#[test]
+fn cursor_on_hint() {
+    let mut game = opened_game();
+    let pos = find_tile(&game, |tile| {
+        tile.status() == TileStatus::Opened && tile.content() != TileContent::Hint(0)
+    });
+    move_to(&mut game, pos);
+    assert_snapshot("cursor_on_hint", &capture_game(&game));
+}
+
+#[test]
+fn game_over_popup() {
+    let mut game = opened_game();
+    let pos = find_tile(&game, |tile| tile.content() == TileContent::Bomb);
+    move_to(&mut game, pos);
+    game.button_click(GameButton::A);
+    assert!(game.status() == GameStatus::GameOver);
+    assert_snapshot("game_over_popup", &capture_game(&game));
+}
+
+#[test]
+fn win_popup() {
+    let mut game = opened_game();
+    for _ in 0..42 * Minesweeper::TICKS_PER_SECOND {
+        game.tick();
+    }
+    while game.status() == GameStatus::Playing {
+        let pos = find_tile(&game, |tile| tile.status() == TileStatus::Closed);
+        move_to(&mut game, pos);
+        if game.board().tile_at(pos).content() == TileContent::Bomb {
+            game.button_click(GameButton::B);
+        } else {
+            game.button_click(GameButton::A);
+        }
+    }
+    assert!(game.status() == GameStatus::Win);
+    assert_snapshot("win_popup", &capture_game(&game));
+}
+
+#[test]
+fn pause_popups() {
+    let mut game = opened_game();
+    game.pause(Pause::Disconnected);
+    assert_snapshot("disconnected_popup", &capture_game(&game));
+    game.pause(Pause::Calibration);
+    assert_snapshot("calibration_popup", &capture_game(&game));
+}
+
+#[test]
+fn pause_popup_over_menu() {
+    let mut game = new_game(Difficulty::Easy);
+    game.pause(Pause::Disconnected);
+    let mut menu = Menu::new();
+    let mut settings = Settings::default();
+    open_items(&mut menu, &mut settings, &[3]);
+    let frame = capture(&game, &menu, &settings, &Stats::default());
+    assert_snapshot("disconnected_over_menu", &frame);
+}
+
+#[test]
+fn dismissed_popup_leaves_no_trace() {
+    let mut game = new_game(Difficulty::Easy);
+    let mut menu = Menu::new();
+    let mut settings = Settings::default();
+    open_items(&mut menu, &mut settings, &[4]);
+    let stats = Stats::default();
+
+    let mut ui = GameUI::new();
+    let mut frame = FrameBuffer::new();
+    game.pause(Pause::Calibration);
+    for _ in 0..2 {
+        ui.update(&UiState {
+            game: &game,
+            menu: &menu,
+            settings: &settings,
+            stats: &stats,
+        });
+        ui.render(&mut frame);
+        game.resume();
+    }
+    assert!(frame == capture(&game, &menu, &settings, &stats));
+}
+
+#[test]
+fn hud_values() {
+    let mut game = opened_game();
+    for _ in 0..3 {
+        let pos = find_tile(&game, |tile| tile.status() == TileStatus::Closed);
+        move_to(&mut game, pos);
+        game.button_click(GameButton::B);
+    }
+    for _ in 0..83 * Minesweeper::TICKS_PER_SECOND {
+        game.tick();
+    }
+    assert_eq!(game.elapsed_secs(), 83);
+    assert_snapshot("hud_values", &capture_game(&game));
+}
+
+#[test]
+fn scrolled_board() {
+    for &difficulty in [Difficulty::Normal, Difficulty::Hard].iter() {
+        let mut game = new_game(difficulty);
+        let config = difficulty.config();
+        move_to(
+            &mut game,
+            Point::new(config.width as i32 / 2, config.height as i32 / 2),
+        );
+        game.button_click(GameButton::A);
+        let name = format!("scrolled_board_{}x{}", config.width, config.height);
+        assert_snapshot(&name, &capture_game(&game));
+    }
+}
@@ -1,6 +1,7 @@
 /* Linker script for the STM32G030F6Px */
 MEMORY
 {
-  FLASH : ORIGIN = 0x08000000, LENGTH = 32K
+  /* Last two 2K pages are reserved for settings storage */
+  FLASH : ORIGIN = 0x08000000, LENGTH = 28K
   RAM : ORIGIN = 0x20000000, LENGTH = 8K
 }
\ No newline at end of file
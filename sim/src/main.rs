@@ -0,0 +1,317 @@
+//! Terminal front end for the game core, for playing and debugging without hardware.
+
+use std::io::{self, Write};
+use std::process;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind};
+use crossterm::style::{Attribute, Print, SetAttribute};
+use crossterm::{cursor, execute, queue, terminal};
+use klaptik::{Glyph, Point};
+use minesweeper_core::board::Board;
+use minesweeper_core::challenge::*;
+use minesweeper_core::game::*;
+use minesweeper_core::menu::Menu;
+use minesweeper_core::settings::{Difficulty, Settings};
+use minesweeper_core::stats::Stats;
+use minesweeper_core::tiles::TileSize;
+use minesweeper_core::ui::{format_time, UiState};
+use minesweeper_frame::FrameBuffer;
+
+const USAGE: &str = "\
+Usage: minesweeper-sim [--seed N] [--mines N] [--size WxH]
+                       [--daily YYYY-MM-DD] [--code XXXXX-XXXXX]
+
+--daily seeds the board like the daily challenge on that date, --code plays a
+board code shown by the console.
+
+Keys: arrows move, space opens, f flags, c chords, r restarts, p saves a screenshot, q quits";
+
+const TICK: Duration = Duration::from_millis(1000 / Minesweeper::TICKS_PER_SECOND as u64);
+
+struct Options {
+    seed: u32,
+    config: GameConfig,
+}
+
+impl Options {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut seed = None;
+        let mut width = 16;
+        let mut height = 6;
+        let mut bombs = 8;
+        let mut code = None;
+
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or(format!("missing value for {}", arg));
+            match arg.as_str() {
+                "--seed" => seed = Some(parse_number(&value()?)?),
+                "--daily" => {
+                    let date = value()?;
+                    seed = Some(parse_date(&date)?.seed());
+                }
+                "--code" => {
+                    let value = value()?;
+                    code = Some(
+                        BoardCode::parse(&value)
+                            .ok_or(format!("not a valid board code: {}", value))?,
+                    );
+                }
+                "--mines" => bombs = parse_number(&value()?)? as usize,
+                "--size" => {
+                    let size = value()?;
+                    let (w, h) = size
+                        .split_once('x')
+                        .ok_or(format!("size must look like 16x6, got {}", size))?;
+                    width = parse_number(w)? as usize;
+                    height = parse_number(h)? as usize;
+                }
+                "-h" | "--help" => return Err(String::new()),
+                _ => return Err(format!("unknown option {}", arg)),
+            }
+        }
+
+        if let Some(code) = code {
+            return Ok(Self {
+                seed: code.seed(),
+                config: code.config(),
+            });
+        }
+
+        if width == 0 || width > Board::MAX_WIDTH || height == 0 || height > Board::MAX_HEIGHT {
+            return Err(format!(
+                "board size must be within {}x{}",
+                Board::MAX_WIDTH,
+                Board::MAX_HEIGHT
+            ));
+        }
+        if bombs >= width * height {
+            return Err(format!(
+                "{} mines do not fit a {}x{} board",
+                bombs, width, height
+            ));
+        }
+
+        let seed = seed.unwrap_or_else(|| {
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default();
+            now.subsec_nanos()
+        });
+        let config = GameConfig {
+            width,
+            height,
+            bombs,
+            tiles: TileSize::Large,
+        };
+        Ok(Self { seed, config })
+    }
+}
+
+fn parse_number(value: &str) -> Result<u32, String> {
+    value
+        .parse()
+        .map_err(|_| format!("expected a number, got {}", value))
+}
+
+fn parse_date(value: &str) -> Result<Date, String> {
+    let invalid = || format!("expected a date like 2025-01-31, got {}", value);
+    let mut parts = value.splitn(3, '-').map(|part| part.parse::<u16>().ok());
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(Some(year)), Some(Some(month)), Some(Some(day))) => {
+            Date::new(year, month as u8, day as u8).ok_or_else(invalid)
+        }
+        _ => Err(invalid()),
+    }
+}
+
+/// Code of the board, when it matches one of the console's difficulties.
+fn board_code(game: &Minesweeper) -> Option<BoardCode> {
+    Difficulty::from_config(&game.config())
+        .map(|difficulty| BoardCode::new(difficulty, game.board_seed()))
+}
+
+fn main() {
+    let options = match Options::parse(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(err) if err.is_empty() => {
+            println!("{}", USAGE);
+            return;
+        }
+        Err(err) => {
+            eprintln!("{}\n\n{}", err, USAGE);
+            process::exit(2);
+        }
+    };
+
+    let mut stdout = io::stdout();
+    let res = terminal::enable_raw_mode()
+        .and_then(|_| execute!(stdout, terminal::EnterAlternateScreen, cursor::Hide))
+        .and_then(|_| run(&mut stdout, &options));
+    execute!(stdout, cursor::Show, terminal::LeaveAlternateScreen).ok();
+    terminal::disable_raw_mode().ok();
+
+    if let Err(err) = res {
+        eprintln!("terminal error: {}", err);
+        process::exit(1);
+    }
+}
+
+fn run(out: &mut impl Write, options: &Options) -> io::Result<()> {
+    let mut seed = options.seed;
+    let mut game = new_game(options.config, seed);
+    let mut last_tick = Instant::now();
+    let mut screenshots = 0;
+    let mut message = String::new();
+
+    loop {
+        draw(out, &game, &message)?;
+
+        if event::poll(TICK.saturating_sub(last_tick.elapsed()))? {
+            if let Event::Key(KeyEvent {
+                code,
+                kind: KeyEventKind::Press,
+                ..
+            }) = event::read()?
+            {
+                match code {
+                    KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                    KeyCode::Char('r') => {
+                        seed = seed.wrapping_add(1);
+                        game = new_game(options.config, seed);
+                    }
+                    KeyCode::Char('p') => {
+                        screenshots += 1;
+                        let path = format!("minesweeper-{}-{}.png", seed, screenshots);
+                        message = match screenshot(&game).save(&path) {
+                            Ok(()) => format!("saved {}", path),
+                            Err(err) => format!("failed to save {}: {}", path, err),
+                        };
+                    }
+                    code => {
+                        if let Some(button) = button(code) {
+                            game.button_click(button);
+                        }
+                    }
+                }
+            }
+        }
+
+        if last_tick.elapsed() >= TICK {
+            last_tick = Instant::now();
+            game.tick();
+        }
+    }
+}
+
+fn new_game(config: GameConfig, seed: u32) -> Minesweeper {
+    let mut game = Minesweeper::new(config);
+    game.seed_random(seed);
+    game
+}
+
+/// Renders the game screen the way the device would show it.
+fn screenshot(game: &Minesweeper) -> FrameBuffer {
+    let mut menu = Menu::new();
+    menu.enter_game();
+    FrameBuffer::capture(&UiState {
+        game,
+        menu: &menu,
+        settings: &Settings::default(),
+        stats: &Stats::default(),
+    })
+}
+
+fn button(code: KeyCode) -> Option<GameButton> {
+    match code {
+        KeyCode::Up => Some(GameButton::DPad(Dir::Up)),
+        KeyCode::Right => Some(GameButton::DPad(Dir::Right)),
+        KeyCode::Down => Some(GameButton::DPad(Dir::Down)),
+        KeyCode::Left => Some(GameButton::DPad(Dir::Left)),
+        KeyCode::Char(' ') => Some(GameButton::A),
+        KeyCode::Char('f') => Some(GameButton::B),
+        KeyCode::Char('c') => Some(GameButton::Chord),
+        _ => None,
+    }
+}
+
+/// Terminal characters for the `GAME_TILES` glyphs a tile maps to.
+fn glyph_char(glyph: Glyph) -> char {
+    match glyph {
+        b',' => ' ',
+        b'-' => '#',
+        b'.' => 'F',
+        b'/' => '*',
+        b'1'..=b'8' => glyph as char,
+        _ => '?',
+    }
+}
+
+fn draw(out: &mut impl Write, game: &Minesweeper, message: &str) -> io::Result<()> {
+    let board = game.board();
+    queue!(
+        out,
+        cursor::MoveTo(0, 0),
+        terminal::Clear(terminal::ClearType::All)
+    )?;
+
+    let status = match game.status() {
+        GameStatus::Bootstrap => "ready".to_string(),
+        GameStatus::Playing => "playing".to_string(),
+        GameStatus::Win => format!(
+            "you win! 3BV {}, {:.2} 3BV/s, {}% efficiency, press space for a new board",
+            game.three_bv(),
+            game.three_bv_rate() as f32 / 100.0,
+            game.efficiency()
+        ),
+        GameStatus::GameOver => format!(
+            "boom! 3BV {} in {} clicks, press space for a new board",
+            game.three_bv(),
+            game.clicks()
+        ),
+    };
+    let time = format_time(game.elapsed_secs());
+    // Boards after the first are dealt from the generator, not the option seed
+    let board_id = match board_code(game) {
+        _ if game.status() == GameStatus::Bootstrap => "board dealt on first open".to_string(),
+        Some(code) => format!("board {}", String::from_utf8_lossy(&code.format())),
+        None => format!("seed {}", game.board_seed()),
+    };
+    queue!(
+        out,
+        Print(format!(
+            "mines {:3}   time {}   {}   {}",
+            game.mines_left(),
+            String::from_utf8_lossy(&time),
+            board_id,
+            status
+        ))
+    )?;
+
+    for y in 0..board.height() {
+        queue!(out, cursor::MoveTo(0, y as u16 + 2))?;
+        for x in 0..board.width() {
+            let pos = Point::new(x as i32, y as i32);
+            let glyph = glyph_char(Glyph::from(&board.tile_at(pos)));
+            if pos == board.cursor() {
+                queue!(
+                    out,
+                    SetAttribute(Attribute::Reverse),
+                    Print(format!("{} ", glyph)),
+                    SetAttribute(Attribute::Reset)
+                )?;
+            } else {
+                queue!(out, Print(format!("{} ", glyph)))?;
+            }
+        }
+    }
+
+    queue!(
+        out,
+        cursor::MoveTo(0, board.height() as u16 + 3),
+        Print("arrows move, space opens, f flags, c chords, r restarts, p saves a screenshot, q quits"),
+        cursor::MoveTo(0, board.height() as u16 + 4),
+        Print(message)
+    )?;
+    out.flush()
+}
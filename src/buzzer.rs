@@ -0,0 +1,30 @@
+use hal::prelude::*;
+use hal::stm32::TIM14;
+use hal::timer::pwm::{Pwm, PwmPin};
+use hal::timer::Channel1;
+
+/// Piezo buzzer on TIM14 channel 1, driven with a square wave.
+pub struct Buzzer {
+    pwm: Pwm<TIM14>,
+    channel: PwmPin<TIM14, Channel1>,
+}
+
+impl Buzzer {
+    pub fn new(pwm: Pwm<TIM14>, channel: PwmPin<TIM14, Channel1>) -> Self {
+        let mut buzzer = Self { pwm, channel };
+        buzzer.set_tone(0);
+        buzzer
+    }
+
+    /// Plays `freq` Hz, 0 silences the buzzer.
+    pub fn set_tone(&mut self, freq: u16) {
+        if freq == 0 {
+            self.channel.disable();
+            return;
+        }
+        self.pwm.set_freq((freq as u32).Hz());
+        let duty = self.channel.get_max_duty() / 2;
+        self.channel.set_duty(duty);
+        self.channel.enable();
+    }
+}
@@ -0,0 +1,68 @@
+use core::fmt;
+
+use rtt_target::DownChannel;
+
+use minesweeper_core::console::Console;
+use minesweeper_core::game::Minesweeper;
+
+/// Console commands read from an RTT down channel, replies logged via defmt.
+pub struct DebugConsole {
+    channel: DownChannel,
+    console: Console,
+    out: DefmtLines,
+}
+
+impl DebugConsole {
+    pub fn new(channel: DownChannel) -> Self {
+        Self {
+            channel,
+            console: Console::new(),
+            out: DefmtLines::default(),
+        }
+    }
+
+    /// Next byte typed into the console, if any.
+    pub fn read(&mut self) -> Option<u8> {
+        let mut byte = [0];
+        match self.channel.read(&mut byte) {
+            0 => None,
+            _ => Some(byte[0]),
+        }
+    }
+
+    pub fn feed(&mut self, byte: u8, game: &mut Minesweeper) {
+        self.console.feed(byte, game, &mut self.out).ok();
+    }
+}
+
+/// Buffers text and prints it a line at a time.
+struct DefmtLines {
+    line: [u8; 64],
+    len: usize,
+}
+
+impl Default for DefmtLines {
+    fn default() -> Self {
+        Self {
+            line: [0; 64],
+            len: 0,
+        }
+    }
+}
+
+impl fmt::Write for DefmtLines {
+    fn write_str(&mut self, text: &str) -> fmt::Result {
+        for byte in text.bytes() {
+            if byte == b'\n' || self.len == self.line.len() {
+                let line = core::str::from_utf8(&self.line[..self.len]).unwrap_or("");
+                defmt::println!("{=str}", line);
+                self.len = 0;
+            }
+            if byte != b'\n' {
+                self.line[self.len] = byte;
+                self.len += 1;
+            }
+        }
+        Ok(())
+    }
+}
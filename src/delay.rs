@@ -0,0 +1,30 @@
+use hal::hal::blocking::delay::{DelayMs, DelayUs};
+use hal::rcc::Rcc;
+
+/// Busy wait counted in core cycles, only the display reset needs a delay.
+///
+/// The HAL delay works periods out with 64-bit division, a runtime routine
+/// that takes more flash than this whole delay.
+pub struct SpinDelay {
+    cycles_per_us: u32,
+}
+
+impl SpinDelay {
+    pub fn new(rcc: &Rcc) -> Self {
+        Self {
+            cycles_per_us: rcc.clocks.core_clk.raw() / 1_000_000,
+        }
+    }
+}
+
+impl DelayUs<u32> for SpinDelay {
+    fn delay_us(&mut self, us: u32) {
+        cortex_m::asm::delay(us.saturating_mul(self.cycles_per_us));
+    }
+}
+
+impl DelayMs<u8> for SpinDelay {
+    fn delay_ms(&mut self, ms: u8) {
+        self.delay_us(ms as u32 * 1_000);
+    }
+}
@@ -6,39 +6,88 @@ extern crate panic_halt;
 extern crate rtic;
 extern crate stm32g0xx_hal as hal;
 
-mod board;
-mod game;
-mod sprites;
+#[cfg(feature = "sound")]
+mod buzzer;
+#[cfg(feature = "debug-console")]
+mod console;
+mod delay;
+mod sleep;
+mod storage;
 mod wiring;
 
+// The debug console sets up RTT itself and routes defmt through it, without
+// either the logger would only take up flash and RAM
+#[cfg(all(feature = "log-warn", not(feature = "debug-console")))]
 use defmt_rtt as _;
 
 use hal::gpio::*;
 use hal::i2c;
 use hal::prelude::*;
+use hal::serial;
 use klaptik::drivers::st7567::*;
 use klaptik::*;
-use wii_ext::nunchuk::*;
 
-use crate::game::*;
+use minesweeper_core::controller::*;
+use minesweeper_core::demo::*;
+use minesweeper_core::extension::*;
+use minesweeper_core::game::*;
+use minesweeper_core::idle::*;
+use minesweeper_core::input::*;
+use minesweeper_core::menu::*;
+use minesweeper_core::remote::*;
+use minesweeper_core::settings::*;
+use minesweeper_core::solver::*;
+#[cfg(feature = "sound")]
+use minesweeper_core::sound::*;
+use minesweeper_core::stats::*;
+use minesweeper_core::ui::*;
+
+#[cfg(feature = "sound")]
+use crate::buzzer::*;
+#[cfg(feature = "debug-console")]
+use crate::console::*;
+use crate::delay::*;
+use crate::sleep::*;
+use crate::storage::*;
 use crate::wiring::*;
 
-#[rtic::app(device = hal::stm32, peripherals = true)]
+// RTIC checks that every local resource is `Send`, including the ones compiled
+// out, so their types have to resolve without the feature
+#[cfg(not(feature = "sound"))]
+type Buzzer = ();
+#[cfg(not(feature = "sound"))]
+type Sequencer = ();
+#[cfg(not(feature = "sound"))]
+type SoundTimer = ();
+#[cfg(not(feature = "debug-console"))]
+type DebugConsole = ();
+
+#[rtic::app(device = hal::stm32, peripherals = true, dispatchers = [I2C1])]
 mod app {
     use super::*;
 
-    const BOMBS: usize = 8;
-
     #[shared]
     struct Shared {
         #[lock_free]
         game: Minesweeper,
         #[lock_free]
-        render_timer: RenderTimer,
+        menu: Menu,
+        #[lock_free]
+        settings: Settings,
+        #[lock_free]
+        stats: Stats,
+        #[lock_free]
+        storage: SettingsStorage,
         #[lock_free]
         input_timer: InputTimer,
         #[lock_free]
         rng_timer: RngTimer,
+        #[lock_free]
+        idle: IdleMonitor,
+        #[lock_free]
+        power: PowerControl,
+        #[lock_free]
+        uptime: u32,
     }
 
     #[local]
@@ -46,26 +95,63 @@ mod app {
         display: DisplayController,
         ui: GameUI,
         nunchuk: Joystick,
+        mapper: InputMapper,
+        demo: Demo<Solver>,
+        remote: Remote,
+        remote_rx: RemoteRx,
+        remote_tx: RemoteTx,
+        #[cfg(feature = "sound")]
+        buzzer: Buzzer,
+        #[cfg(feature = "sound")]
+        sequencer: Sequencer,
+        #[cfg(feature = "sound")]
+        sound_timer: SoundTimer,
+        #[cfg(feature = "debug-console")]
+        console: DebugConsole,
     }
 
     #[init]
     fn init(ctx: init::Context) -> (Shared, Local, init::Monotonics) {
+        #[cfg(feature = "debug-console")]
+        let console = {
+            let channels = rtt_target::rtt_init! {
+                up: {
+                    0: { size: 1024, name: "defmt" }
+                }
+                down: {
+                    0: { size: 64, name: "Terminal" }
+                }
+            };
+            rtt_target::set_defmt_channel(channels.up.0);
+            DebugConsole::new(channels.down.0)
+        };
+
         let mut rcc = ctx.device.RCC.constrain();
         let port_a = ctx.device.GPIOA.split(&mut rcc);
+        let port_b = ctx.device.GPIOB.split(&mut rcc);
 
-        let mut delay = ctx.device.TIM1.delay(&mut rcc);
+        let mut delay = SpinDelay::new(&rcc);
 
         let mut rng_timer = ctx.device.TIM3.timer(&mut rcc);
         rng_timer.resume();
 
-        let mut render_timer = ctx.device.TIM14.timer(&mut rcc);
-        render_timer.start(40.millis());
-        render_timer.listen();
-
-        let mut input_timer = ctx.device.TIM17.timer(&mut rcc);
-        input_timer.start(100.millis());
+        let mut input_timer = InputTimer::new(ctx.device.TIM17, &mut rcc);
+        input_timer.start(PowerState::Active.poll_interval_ms());
         input_timer.listen();
 
+        #[cfg(feature = "sound")]
+        let (buzzer, sound_timer) = {
+            let pwm = ctx.device.TIM14.pwm(1.kHz(), &mut rcc);
+            let channel = pwm.bind_pin(port_b.pb1);
+            let mut sound_timer = ctx.device.TIM16.timer(&mut rcc);
+            sound_timer.start(Sequencer::TICK_MS.millis());
+            sound_timer.listen();
+            (Buzzer::new(pwm, channel), sound_timer)
+        };
+
+        let power = PowerControl::new(ctx.device.RTC, ctx.core.SCB);
+        let idle = IdleMonitor::new(IdlePolicy::default(), 0);
+
         let spi = ctx.device.SPI2.spi(
             (port_a.pa0, hal::spi::NoMiso, port_a.pa4),
             hal::spi::MODE_0,
@@ -85,71 +171,441 @@ mod app {
         let scl = port_a.pa11.into_open_drain_output();
         let i2c_config = i2c::Config::new(100.kHz());
         let i2c = ctx.device.I2C2.i2c(sda, scl, i2c_config, &mut rcc);
-        let nunchuk = Nunchuk::new(i2c, &mut delay).unwrap();
+        let nunchuk = Controller::new(i2c);
 
-        let game = Minesweeper::new(BOMBS);
+        let mut serial = ctx
+            .device
+            .USART1
+            .usart(
+                (port_b.pb6, port_b.pb7),
+                serial::FullConfig::default().baudrate(115_200.bps()),
+                &mut rcc,
+            )
+            .unwrap();
+        serial.listen(serial::Event::Rxne);
+        let (remote_tx, remote_rx) = serial.split();
+
+        let mut storage = SettingsStorage::new(ctx.device.FLASH);
+        let settings = storage.load().unwrap_or_default();
+        let stats = storage.load_stats().unwrap_or_default();
+
+        let game = Minesweeper::new(settings.difficulty.config());
+        let menu = Menu::new();
         let mut ui = GameUI::new();
-        ui.update(&game);
+        ui.update(&UiState {
+            game: &game,
+            menu: &menu,
+            settings: &settings,
+            stats: &stats,
+        });
 
         port_a.pa6.into_open_drain_output_in_state(PinState::Low);
 
         (
             Shared {
                 game,
+                menu,
+                settings,
+                stats,
+                storage,
                 input_timer,
-                render_timer,
                 rng_timer,
+                idle,
+                power,
+                uptime: 0,
             },
             Local {
                 ui,
                 display,
                 nunchuk,
+                mapper: InputMapper::default(),
+                demo: Demo::new(Solver::new()),
+                remote: Remote::new(),
+                remote_rx,
+                remote_tx,
+                #[cfg(feature = "sound")]
+                buzzer,
+                #[cfg(feature = "sound")]
+                sequencer: Sequencer::new(),
+                #[cfg(feature = "sound")]
+                sound_timer,
+                #[cfg(feature = "debug-console")]
+                console,
             },
             init::Monotonics(),
         )
     }
 
-    #[task(binds = TIM14, local = [display, ui], shared = [game, render_timer])]
-    fn render_timer_tick(ctx: render_timer_tick::Context) {
-        let render_timer_tick::LocalResources { display, ui } = ctx.local;
-        let render_timer_tick::SharedResources { game, render_timer } = ctx.shared;
+    #[task(
+        local = [display, ui, rendered: Option<u32> = None, display_on: bool = true],
+        shared = [game, menu, settings, stats, idle]
+    )]
+    fn render(ctx: render::Context) {
+        let render::LocalResources {
+            display,
+            ui,
+            rendered,
+            display_on,
+        } = ctx.local;
+        let render::SharedResources {
+            game,
+            menu,
+            settings,
+            stats,
+            idle,
+        } = ctx.shared;
+
+        // Display RAM survives blanking, so the screen comes back as it was
+        let awake = idle.state() == PowerState::Active;
+        if awake != *display_on {
+            *display_on = awake;
+            if awake {
+                display.on();
+            } else {
+                display.off();
+            }
+        }
+        if !awake {
+            return;
+        }
+
+        let revision = game.revision().wrapping_add(menu.revision());
+        if *rendered == Some(revision) {
+            return;
+        }
+        *rendered = Some(revision);
 
-        ui.update(&game);
+        ui.update(&UiState {
+            game,
+            menu,
+            settings,
+            stats,
+        });
         ui.render(display);
+    }
 
-        render_timer.clear_irq();
+    #[cfg(feature = "sound")]
+    #[task(
+        binds = TIM16,
+        local = [buzzer, sequencer, sound_timer],
+        shared = [game, settings, idle]
+    )]
+    fn sound_timer_tick(ctx: sound_timer_tick::Context) {
+        let sound_timer_tick::LocalResources {
+            buzzer,
+            sequencer,
+            sound_timer,
+            ..
+        } = ctx.local;
+        let sound_timer_tick::SharedResources {
+            game,
+            settings,
+            idle,
+        } = ctx.shared;
+
+        sound_timer.clear_irq();
+
+        let awake = idle.state() == PowerState::Active;
+        if let Some(event) = game.take_event() {
+            if settings.sound && awake {
+                sequencer.play(event.into());
+            }
+        }
+        if !settings.sound || !awake {
+            sequencer.stop();
+        }
+        if let Some(freq) = sequencer.tick() {
+            buzzer.set_tone(freq);
+        }
+
+        // No ticks while the screen is blank, `wake` pends this task to restart them
+        if awake {
+            sound_timer.resume();
+        } else {
+            sound_timer.pause();
+        }
     }
 
-    #[task(binds = TIM17, local = [nunchuk], shared = [game, input_timer, rng_timer])]
+    #[task(
+        binds = USART1,
+        local = [remote, remote_rx, remote_tx],
+        shared = [game, menu, settings, stats, storage, input_timer, idle, power, uptime]
+    )]
+    fn remote_input(ctx: remote_input::Context) {
+        let remote_input::LocalResources {
+            remote,
+            remote_rx,
+            remote_tx,
+        } = ctx.local;
+        let remote_input::SharedResources {
+            game,
+            menu,
+            settings,
+            stats,
+            storage,
+            input_timer,
+            idle,
+            power,
+            uptime,
+        } = ctx.shared;
+
+        while let Ok(byte) = remote_rx.read() {
+            let command = match remote.feed(byte) {
+                Some(Ok(command)) => command,
+                Some(Err(err)) => {
+                    reply_error(err, remote_tx).ok();
+                    continue;
+                }
+                None => continue,
+            };
+
+            wake(idle.remote(*uptime), power, input_timer);
+            if let Command::NewGame { .. } = command {
+                menu.enter_game();
+            }
+            let finished = menu.play(game, stats, |game| {
+                execute(command, game, remote_tx).ok();
+            });
+            if finished {
+                storage.save(settings, stats);
+            }
+        }
+        render::spawn().ok();
+    }
+
+    #[cfg(feature = "debug-console")]
+    #[task(local = [console], shared = [game, menu, settings, stats, storage])]
+    fn debug_console(ctx: debug_console::Context) {
+        let debug_console::SharedResources {
+            game,
+            menu,
+            settings,
+            stats,
+            storage,
+        } = ctx.shared;
+        let console = ctx.local.console;
+        while let Some(byte) = console.read() {
+            if menu.play(game, stats, |game| console.feed(byte, game)) {
+                storage.save(settings, stats);
+            }
+        }
+    }
+
+    #[task(binds = RTC_TAMP, shared = [power])]
+    fn wakeup_timer_tick(ctx: wakeup_timer_tick::Context) {
+        ctx.shared.power.clear_wakeup();
+        // Poll the controller as if the input timer fired
+        rtic::pend(hal::stm32::Interrupt::TIM17);
+    }
+
+    #[task(
+        binds = TIM17,
+        local = [
+            nunchuk,
+            mapper,
+            demo,
+            calibrator: Option<Calibrator> = None,
+            booting: bool = true,
+        ],
+        shared = [
+            game,
+            menu,
+            settings,
+            stats,
+            storage,
+            input_timer,
+            rng_timer,
+            idle,
+            power,
+            uptime,
+        ]
+    )]
     fn input_timer_tick(ctx: input_timer_tick::Context) {
-        let input_timer_tick::LocalResources { nunchuk } = ctx.local;
+        let input_timer_tick::LocalResources {
+            nunchuk,
+            mapper,
+            demo,
+            calibrator,
+            booting,
+        } = ctx.local;
         let input_timer_tick::SharedResources {
             game,
+            menu,
+            settings,
+            stats,
+            storage,
             input_timer,
             rng_timer,
+            idle,
+            power,
+            uptime,
         } = ctx.shared;
-        let state = nunchuk.read_no_wait().unwrap();
 
-        if state.button_z {
-            game.seed_random(rng_timer.get_current());
-            game.button_click(GameButton::A);
+        input_timer.clear_irq();
+        // Runs after this tick is handled and redraws only if something changed
+        render::spawn().ok();
+        #[cfg(feature = "debug-console")]
+        debug_console::spawn().ok();
+
+        // Game clock stops while the screen is blank
+        let changed = idle.poll(uptime, || {
+            if menu.screen() == Screen::Game {
+                game.tick();
+            }
+        });
+        if let Some(state) = changed {
+            power.apply(state, input_timer);
         }
-        if state.button_c {
-            game.button_click(GameButton::B);
+
+        let reading = match nunchuk.poll() {
+            ControllerEvent::Input(reading) => reading,
+            ControllerEvent::Connected => {
+                mapper.reset();
+                wake(idle.activity(*uptime), power, input_timer);
+                return game.resume();
+            }
+            ControllerEvent::Disconnected => {
+                *calibrator = None;
+                return game.pause(Pause::Disconnected);
+            }
+            ControllerEvent::Idle => return,
+        };
+
+        let booted = core::mem::replace(booting, false);
+        if let Reading::Nunchuk(state) = &reading {
+            if booted && state.button_c && state.button_z {
+                start_calibration(calibrator, game, menu);
+            }
+
+            if let Some(active) = calibrator {
+                wake(idle.activity(*uptime), power, input_timer);
+                if active.sample(state) {
+                    active.apply(settings);
+                    storage.save(settings, stats);
+                    *calibrator = None;
+                    game.resume();
+                }
+                // Track buttons so the press finishing calibration is not replayed
+                mapper.map(settings, &reading, |_| {});
+                mapper.swallow();
+                return;
+            }
         }
 
-        if state.joystick_x > (127 + 64) {
-            game.button_click(GameButton::DPad(Dir::Right));
-        } else if state.joystick_x < (127 - 64) {
-            game.button_click(GameButton::DPad(Dir::Left));
+        if demo.is_running() {
+            let mut touched = false;
+            mapper.map(settings, &reading, |_| touched = true);
+            if touched || idle.state() != PowerState::Active {
+                // Any input hands the device back, the demo board is thrown away
+                demo.stop();
+                game.restart(settings.difficulty.config());
+                menu.open();
+                if touched {
+                    wake(idle.activity(*uptime), power, input_timer);
+                }
+            } else {
+                demo.step(game);
+            }
+            return;
         }
 
-        if state.joystick_y > (127 + 64) {
-            game.button_click(GameButton::DPad(Dir::Up));
-        } else if state.joystick_y < (127 - 64) {
-            game.button_click(GameButton::DPad(Dir::Down));
+        if menu.screen() == Screen::Title
+            && !game.in_progress()
+            && idle.state() == PowerState::Active
+            && idle.idle_ms(*uptime) >= Demo::<Solver>::START_AFTER_MS
+        {
+            start_game(settings.difficulty, rng_timer.get_current(), game, menu);
+            demo.start();
+            return;
         }
 
-        input_timer.clear_irq();
+        let current = *settings;
+        let mut finished = false;
+        mapper.map(&current, &reading, |action| {
+            // First input after idling only wakes the screen up
+            let woke = idle.input(*uptime, || {
+                if menu.screen() == Screen::Game {
+                    let button = match resolve_chord(&current, action, game) {
+                        Action::Open => {
+                            // Boards seeded from a code or the remote keep their seed
+                            game.seed_entropy(rng_timer.get_current());
+                            GameButton::A
+                        }
+                        Action::Flag => GameButton::B,
+                        Action::Chord => GameButton::Chord,
+                        Action::Move(dir) => GameButton::DPad(dir),
+                        Action::Menu => {
+                            menu.open();
+                            return;
+                        }
+                    };
+                    finished |= menu.play(game, stats, |game| game.button_click(button));
+                    return;
+                }
+
+                match menu.handle(action, settings) {
+                    MenuEvent::NewGame => {
+                        // Drops the seed of a challenge that was never opened
+                        start_game(settings.difficulty, rng_timer.get_current(), game, menu);
+                    }
+                    MenuEvent::Continue if game.in_progress() => menu.enter_game(),
+                    MenuEvent::SaveSettings => {
+                        storage.save(settings, stats);
+                    }
+                    MenuEvent::Calibrate if matches!(reading, Reading::Nunchuk(_)) => {
+                        start_calibration(calibrator, game, menu)
+                    }
+                    MenuEvent::ResetStats => {
+                        stats.reset();
+                        storage.save(settings, stats);
+                    }
+                    MenuEvent::Challenge => {
+                        if let Some(today) = power.date() {
+                            menu.set_date(today);
+                        }
+                    }
+                    MenuEvent::PlayDaily => {
+                        // An entered date sets the clock for the days to come
+                        let date = menu.date();
+                        if power.date() != Some(date) {
+                            power.set_date(date);
+                        }
+                        start_game(settings.difficulty, date.seed(), game, menu);
+                    }
+                    MenuEvent::PlayCode(code) => {
+                        start_game(code.difficulty(), code.seed(), game, menu)
+                    }
+                    _ => {}
+                }
+            });
+            wake(woke, power, input_timer);
+        });
+
+        // Saved once per finished game to spare the flash
+        if finished {
+            storage.save(settings, stats);
+        }
+    }
+}
+
+/// Powers back up when user activity woke the device from idle.
+fn wake(woke: Option<PowerState>, power: &mut PowerControl, input_timer: &mut InputTimer) {
+    if let Some(state) = woke {
+        power.apply(state, input_timer);
+        #[cfg(feature = "sound")]
+        rtic::pend(hal::stm32::Interrupt::TIM16);
     }
 }
+
+fn start_calibration(calibrator: &mut Option<Calibrator>, game: &mut Minesweeper, menu: &mut Menu) {
+    *calibrator = Some(Calibrator::new());
+    game.pause(Pause::Calibration);
+    menu.enter_game();
+}
+
+/// Deals the board `seed` picks at `difficulty` on the first open.
+fn start_game(difficulty: Difficulty, seed: u32, game: &mut Minesweeper, menu: &mut Menu) {
+    game.restart(difficulty.config());
+    game.seed_random(seed);
+    menu.start_game(difficulty);
+}
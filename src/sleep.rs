@@ -0,0 +1,218 @@
+use cortex_m::peripheral::SCB;
+use hal::rcc::Rcc;
+use hal::stm32::{PWR, RCC, RTC, TIM17};
+use hal::timer::TimerExt;
+
+use minesweeper_core::challenge::Date;
+use minesweeper_core::idle::PowerState;
+
+/// Applies power states: input polling rate, RTC wakeups and sleep depth.
+pub struct PowerControl {
+    wakeup: WakeupTimer,
+    scb: SCB,
+}
+
+impl PowerControl {
+    pub fn new(rtc: RTC, scb: SCB) -> Self {
+        Self {
+            wakeup: WakeupTimer::new(rtc),
+            scb,
+        }
+    }
+
+    pub fn clear_wakeup(&mut self) {
+        self.wakeup.clear_irq();
+    }
+
+    /// Today's date, if one has been set since power-up.
+    pub fn date(&self) -> Option<Date> {
+        self.wakeup.date()
+    }
+
+    pub fn set_date(&mut self, date: Date) {
+        self.wakeup.set_date(date);
+    }
+
+    pub fn apply(&mut self, state: PowerState, input_timer: &mut InputTimer) {
+        match state {
+            PowerState::Sleep => {
+                // TIM17 halts in STOP mode, the RTC takes over polling
+                input_timer.unlisten();
+                self.wakeup.start(state.poll_interval_ms());
+            }
+            _ => {
+                self.wakeup.stop();
+                input_timer.start(state.poll_interval_ms());
+                input_timer.listen();
+            }
+        }
+        set_stop_mode(&mut self.scb, state == PowerState::Sleep);
+    }
+}
+
+/// TIM17 counting milliseconds, it paces input polling while awake.
+///
+/// The HAL timer works periods out with 64-bit division, a runtime routine
+/// that takes more flash than this whole timer.
+pub struct InputTimer {
+    tim: TIM17,
+}
+
+impl InputTimer {
+    pub fn new(tim: TIM17, rcc: &mut Rcc) -> Self {
+        let ticks_per_ms = rcc.clocks.apb_tim_clk.raw() / 1_000;
+        // The HAL still enables and resets the peripheral
+        let tim = tim.timer(rcc).release();
+        tim.psc
+            .write(|w| unsafe { w.psc().bits(ticks_per_ms as u16 - 1) });
+        Self { tim }
+    }
+
+    pub fn start(&mut self, millis: u32) {
+        let reload = millis.clamp(1, u16::MAX as u32) - 1;
+        // URS keeps the update event below from raising the interrupt
+        self.tim
+            .cr1
+            .modify(|_, w| w.cen().clear_bit().urs().set_bit());
+        self.tim.cnt.reset();
+        self.tim.arr.write(|w| unsafe { w.bits(reload) });
+        // Loads the prescaler and reload into their shadow registers
+        self.tim.egr.write(|w| w.ug().set_bit());
+        self.clear_irq();
+        self.tim.cr1.modify(|_, w| w.cen().set_bit());
+    }
+
+    pub fn listen(&mut self) {
+        self.tim.dier.write(|w| w.uie().set_bit());
+    }
+
+    pub fn unlisten(&mut self) {
+        self.tim.dier.write(|w| w.uie().clear_bit());
+    }
+
+    pub fn clear_irq(&mut self) {
+        self.tim.sr.modify(|_, w| w.uif().clear_bit());
+    }
+}
+
+/// RTC wakeup timer clocked from LSI, it keeps running in STOP mode
+/// and brings the MCU back to poll the controller.
+///
+/// The RTC calendar runs from the same clock. With no backup battery or LSE
+/// the date only survives while powered and drifts by the LSI tolerance.
+struct WakeupTimer {
+    rtc: RTC,
+}
+
+impl WakeupTimer {
+    // LSI is ~32 kHz, wakeup clock is RTCCLK / 16
+    const TICKS_PER_SECOND: u32 = 32_000 / 16;
+
+    fn new(rtc: RTC) -> Self {
+        // HAL owns RCC after constrain and has no RTC clock setup
+        let rcc = unsafe { &*RCC::ptr() };
+        let pwr = unsafe { &*PWR::ptr() };
+        rcc.apbenr1
+            .modify(|_, w| w.pwren().set_bit().rtcapben().set_bit());
+        rcc.csr.modify(|_, w| w.lsion().set_bit());
+        while rcc.csr.read().lsirdy().bit_is_clear() {}
+        pwr.cr1.modify(|_, w| w.dbp().set_bit());
+        rcc.bdcr
+            .modify(|_, w| unsafe { w.rtcsel().bits(0b10) }.rtcen().set_bit());
+        Self { rtc }
+    }
+
+    fn start(&mut self, millis: u32) {
+        let ticks = (millis * Self::TICKS_PER_SECOND / 1_000).clamp(1, u16::MAX as u32);
+        self.unlock();
+        self.rtc.cr.modify(|_, w| w.wute().clear_bit());
+        while self.rtc.icsr.read().wutwf().bit_is_clear() {}
+        self.rtc
+            .wutr
+            .write(|w| unsafe { w.wut().bits(ticks as u16 - 1) });
+        self.rtc.cr.modify(|_, w| {
+            unsafe { w.wucksel().bits(0b000) }
+                .wutie()
+                .set_bit()
+                .wute()
+                .set_bit()
+        });
+        self.lock();
+    }
+
+    fn stop(&mut self) {
+        self.unlock();
+        self.rtc
+            .cr
+            .modify(|_, w| w.wutie().clear_bit().wute().clear_bit());
+        self.lock();
+        self.clear_irq();
+    }
+
+    fn date(&self) -> Option<Date> {
+        // Calendar is left at year 0 until set
+        if self.rtc.icsr.read().inits().bit_is_clear() {
+            return None;
+        }
+        let dr = self.rtc.dr.read();
+        let year = dr.yt().bits() * 10 + dr.yu().bits();
+        let month = dr.mt().bit() as u8 * 10 + dr.mu().bits();
+        let day = dr.dt().bits() * 10 + dr.du().bits();
+        Date::new(Date::MIN_YEAR + year as u16, month, day)
+    }
+
+    /// Sets the calendar to midnight of `date`.
+    fn set_date(&mut self, date: Date) {
+        let year = (date.year() - Date::MIN_YEAR) as u8;
+        let (month, day) = (date.month(), date.day());
+        self.unlock();
+        self.rtc.icsr.modify(|_, w| w.init().set_bit());
+        while self.rtc.icsr.read().initf().bit_is_clear() {}
+        // 1 Hz calendar clock from ~32 kHz LSI: 32_000 / 128 / 250
+        self.rtc
+            .prer
+            .write(|w| unsafe { w.prediv_a().bits(127).prediv_s().bits(249) });
+        self.rtc.tr.write(|w| unsafe { w.bits(0) });
+        self.rtc.dr.write(|w| unsafe {
+            w.yt()
+                .bits(year / 10)
+                .yu()
+                .bits(year % 10)
+                .wdu()
+                .bits(date.weekday())
+                .mt()
+                .bit(month >= 10)
+                .mu()
+                .bits(month % 10)
+                .dt()
+                .bits(day / 10)
+                .du()
+                .bits(day % 10)
+        });
+        self.rtc.icsr.modify(|_, w| w.init().clear_bit());
+        self.lock();
+    }
+
+    fn clear_irq(&mut self) {
+        self.rtc.scr.write(|w| w.cwutf().set_bit());
+    }
+
+    fn unlock(&mut self) {
+        self.rtc.wpr.write(|w| unsafe { w.key().bits(0xca) });
+        self.rtc.wpr.write(|w| unsafe { w.key().bits(0x53) });
+    }
+
+    fn lock(&mut self) {
+        self.rtc.wpr.write(|w| unsafe { w.key().bits(0xff) });
+    }
+}
+
+/// Selects STOP (deep sleep) or regular sleep for the WFI that RTIC issues
+/// between interrupts. PWR_CR1.LPMS is left at its reset value, Stop 0.
+fn set_stop_mode(scb: &mut SCB, enabled: bool) {
+    if enabled {
+        scb.set_sleepdeep();
+    } else {
+        scb.clear_sleepdeep();
+    }
+}
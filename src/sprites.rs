@@ -1,87 +0,0 @@
-use klaptik::*;
-
-pub const LOGO: Sprite = Sprite::new(
-    Glyphs::Alphabet(b"~"),
-    Size::new(72, 16),
-    &[
-        0x00, 0xf0, 0xf0, 0x30, 0xf0, 0xe0, 0x30, 0xf0, 0xe0, 0x00, 0xf0, 0xf0, 0x00, 0xf0, 0xf0,
-        0x30, 0xf0, 0xe0, 0x00, 0xe0, 0xf0, 0xb0, 0xf0, 0xe0, 0x00, 0xe0, 0xf0, 0xb0, 0xb0, 0x30,
-        0x00, 0xf0, 0xf0, 0x00, 0xf0, 0xf0, 0x00, 0xf0, 0xf0, 0x00, 0xe0, 0xf0, 0xb0, 0xf0, 0xe0,
-        0x00, 0xe0, 0xf0, 0xb0, 0xf0, 0xe0, 0x00, 0xf0, 0xf0, 0x30, 0xf0, 0xe0, 0x00, 0xe0, 0xf0,
-        0xb0, 0xf0, 0xe0, 0x00, 0xf0, 0xf0, 0xb0, 0xf0, 0xe0, 0x00, 0x00, 0x00, 0x00, 0x0f, 0x0f,
-        0x00, 0x0f, 0x0f, 0x00, 0x0f, 0x0f, 0x00, 0x0f, 0x0f, 0x00, 0x0f, 0x0f, 0x00, 0x0f, 0x0f,
-        0x00, 0x07, 0x0f, 0x0d, 0x0d, 0x0d, 0x00, 0x0c, 0x0d, 0x0d, 0x0f, 0x07, 0x00, 0x00, 0x07,
-        0x0f, 0x07, 0x07, 0x0f, 0x07, 0x00, 0x00, 0x07, 0x0f, 0x0d, 0x0d, 0x0d, 0x00, 0x07, 0x0f,
-        0x0d, 0x0d, 0x0d, 0x00, 0x3f, 0x3f, 0x0c, 0x0f, 0x07, 0x00, 0x07, 0x0f, 0x0d, 0x0d, 0x0d,
-        0x00, 0x0f, 0x0f, 0x01, 0x0f, 0x0e, 0x00, 0x00, 0x00,
-    ],
-);
-
-pub const POPUP: Sprite = Sprite::new(
-    Glyphs::Alphabet(b"WL"),
-    Size::new(74, 32),
-    &[
-        0xff, 0x0f, 0x7f, 0xf1, 0x81, 0x01, 0x0f, 0x7f, 0xf1, 0x81, 0x01, 0x0f, 0x7f, 0xf1, 0x81,
-        0x01, 0x0f, 0x7f, 0xf1, 0x81, 0x01, 0x0f, 0x7f, 0xf1, 0x81, 0x01, 0x0f, 0x7f, 0xf1, 0x81,
-        0x01, 0x0f, 0x7f, 0xf1, 0x81, 0x01, 0x0f, 0x7f, 0xf1, 0x81, 0x01, 0x0f, 0x7f, 0xf1, 0x81,
-        0x01, 0x0f, 0x7f, 0xf1, 0x81, 0x01, 0x0f, 0x7f, 0xf1, 0x81, 0x01, 0x0f, 0x7f, 0xf1, 0x81,
-        0x01, 0x0f, 0x7f, 0xf1, 0x81, 0x01, 0x0f, 0x7f, 0xf1, 0x81, 0x01, 0x0f, 0x7f, 0xff, 0xff,
-        0x02, 0x02, 0x03, 0x03, 0x02, 0x02, 0x02, 0x03, 0x03, 0x02, 0x02, 0x02, 0x03, 0x83, 0x82,
-        0x02, 0x82, 0x83, 0x03, 0x02, 0x82, 0x82, 0x83, 0x03, 0x02, 0x82, 0x82, 0x03, 0x83, 0x82,
-        0x02, 0x02, 0x03, 0x03, 0x02, 0x02, 0x82, 0x83, 0x03, 0x82, 0x82, 0x02, 0x83, 0x83, 0x02,
-        0x02, 0x82, 0x83, 0x83, 0x02, 0x02, 0x82, 0x83, 0x83, 0x82, 0x02, 0x02, 0x83, 0x83, 0x02,
-        0x02, 0x02, 0x03, 0x03, 0x02, 0x02, 0x02, 0x03, 0x03, 0x02, 0x02, 0x02, 0xff, 0xff, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x9f, 0xbf, 0xb0,
-        0xff, 0xff, 0x00, 0x3f, 0x7f, 0x61, 0x7f, 0x3f, 0x00, 0x3f, 0x7f, 0x60, 0x7f, 0x3f, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x07, 0x3f, 0x78, 0x3f, 0x3f, 0x78, 0x3f, 0x07, 0x00, 0x3f,
-        0x7f, 0x61, 0x7f, 0x3f, 0x00, 0x7f, 0x7f, 0x01, 0x7f, 0x7f, 0x00, 0x6f, 0x6f, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0x80, 0x80,
-        0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x81, 0x81, 0x81, 0x81,
-        0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80,
-        0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80,
-        0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80,
-        0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0xff, 0xff, 0x0f, 0x7f, 0xf1,
-        0x81, 0x01, 0x0f, 0x7f, 0xf1, 0x81, 0x01, 0x0f, 0x7f, 0xf1, 0x81, 0x01, 0x0f, 0x7f, 0xf1,
-        0x81, 0x01, 0x0f, 0x7f, 0xf1, 0x81, 0x01, 0x0f, 0x7f, 0xf1, 0x81, 0x01, 0x0f, 0x7f, 0xf1,
-        0x81, 0x01, 0x0f, 0x7f, 0xf1, 0x81, 0x01, 0x0f, 0x7f, 0xf1, 0x81, 0x01, 0x0f, 0x7f, 0xf1,
-        0x81, 0x01, 0x0f, 0x7f, 0xf1, 0x81, 0x01, 0x0f, 0x7f, 0xf1, 0x81, 0x01, 0x0f, 0x7f, 0xf1,
-        0x81, 0x01, 0x0f, 0x7f, 0xf1, 0x81, 0x01, 0x0f, 0x7f, 0xff, 0xff, 0x02, 0x02, 0x03, 0x03,
-        0x02, 0x02, 0x02, 0x03, 0x83, 0x82, 0x82, 0x82, 0x03, 0x83, 0x82, 0x82, 0x82, 0x03, 0x03,
-        0x82, 0x82, 0x82, 0x83, 0x03, 0x82, 0x82, 0x02, 0x03, 0x03, 0x82, 0x82, 0x82, 0x03, 0x03,
-        0x02, 0x02, 0x02, 0x03, 0x03, 0x02, 0x82, 0x82, 0x83, 0x03, 0x02, 0x82, 0x82, 0x03, 0x83,
-        0x82, 0x02, 0x02, 0x83, 0x83, 0x82, 0x02, 0x02, 0x83, 0x83, 0x82, 0x82, 0x02, 0x03, 0x83,
-        0x82, 0x02, 0x02, 0x03, 0x03, 0x02, 0x02, 0x02, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x9f, 0xbf, 0xb1, 0xff, 0xff, 0x00, 0x79, 0x7d, 0x6d, 0x7f, 0x7f, 0x00, 0x7f,
-        0x7f, 0x01, 0x7f, 0x7f, 0x01, 0x7f, 0x7f, 0x00, 0x3f, 0x7f, 0x6d, 0x6f, 0x6f, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x3f, 0x7f, 0x61, 0x7f, 0x3f, 0x00, 0x07, 0x3f, 0x78, 0x3f, 0x07,
-        0x00, 0x3f, 0x7f, 0x6d, 0x6f, 0x6f, 0x00, 0x7f, 0x7f, 0x0d, 0x7f, 0x77, 0x00, 0x6f, 0x6f,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80,
-        0x80, 0x81, 0x81, 0x81, 0x81, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80,
-        0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80,
-        0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80,
-        0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80,
-        0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0xff,
-    ],
-);
-
-pub const GAME_TILES: Sprite = Sprite::new(
-    Glyphs::Alphabet(b",-./0123456789:;<=>?@ABCDE"),
-    Size::new(8, 8),
-    &[
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0c, 0x0c, 0x00, 0x00,
-        0x00, 0x00, 0x02, 0x2a, 0x2a, 0x0e, 0x0e, 0x00, 0x00, 0x0c, 0x3f, 0x3f, 0x7f, 0x7f, 0x3f,
-        0x3f, 0x0c, 0x00, 0x3e, 0x3e, 0x22, 0x3e, 0x3e, 0x00, 0x00, 0x00, 0x02, 0x3e, 0x3e, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x3a, 0x3a, 0x2a, 0x2e, 0x2e, 0x00, 0x00, 0x00, 0x2a, 0x2a, 0x2a,
-        0x3e, 0x3e, 0x00, 0x00, 0x00, 0x1e, 0x1e, 0x10, 0x3e, 0x3e, 0x00, 0x00, 0x00, 0x2e, 0x2e,
-        0x2a, 0x3a, 0x3a, 0x00, 0x00, 0x00, 0x3e, 0x3e, 0x2a, 0x3a, 0x3a, 0x00, 0x00, 0x00, 0x02,
-        0x02, 0x02, 0x3e, 0x3e, 0x00, 0x00, 0x00, 0x3e, 0x3e, 0x2a, 0x3e, 0x3e, 0x00, 0x00, 0xff,
-        0x81, 0x81, 0x81, 0x81, 0x81, 0x81, 0xff, 0xff, 0xff, 0xff, 0xe7, 0xe7, 0xff, 0xff, 0xff,
-        0xff, 0xfb, 0xab, 0xab, 0xe3, 0xe3, 0xff, 0xff, 0xc7, 0x03, 0x01, 0x01, 0x01, 0x01, 0x03,
-        0xc7, 0xff, 0x83, 0x83, 0xbb, 0x83, 0x83, 0xff, 0xff, 0xff, 0xfb, 0x83, 0x83, 0xff, 0xff,
-        0xff, 0xff, 0xff, 0x8b, 0x8b, 0xab, 0xa3, 0xa3, 0xff, 0xff, 0xff, 0xab, 0xab, 0xab, 0x83,
-        0x83, 0xff, 0xff, 0xff, 0xc3, 0xc3, 0xdf, 0x83, 0x83, 0xff, 0xff, 0xff, 0xa3, 0xa3, 0xab,
-        0x8b, 0x8b, 0xff, 0xff, 0xff, 0x83, 0x83, 0xab, 0x8b, 0x8b, 0xff, 0xff, 0xff, 0xfb, 0xfb,
-        0xfb, 0x83, 0x83, 0xff, 0xff, 0xff, 0x83, 0x83, 0xab, 0x83, 0x83, 0xff, 0xff, 0x03, 0x03,
-        0x03, 0x03, 0x03, 0x03, 0x03, 0x03,
-    ],
-);
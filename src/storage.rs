@@ -0,0 +1,140 @@
+use hal::flash::*;
+use hal::stm32::FLASH;
+
+use minesweeper_core::settings::Settings;
+use minesweeper_core::stats::Stats;
+
+// Last two 2K pages, reserved in memory.x
+const PAGES: [FlashPage; 2] = [FlashPage(14), FlashPage(15)];
+const PAGE_SIZE: usize = 2048;
+/// Generation of the page and its complement.
+const HEADER_SIZE: usize = 8;
+/// Settings then stats, both multiples of the 8 byte flash word.
+const SLOT_SIZE: usize = Settings::RECORD_SIZE + Stats::RECORD_SIZE;
+const SLOTS: usize = (PAGE_SIZE - HEADER_SIZE) / SLOT_SIZE;
+
+/// Settings and stats records, appended together to one of two flash pages.
+///
+/// Each save takes the next free slot of the current page. Once it is full
+/// the other page is erased, written and only then given a newer generation
+/// in its header, so a power loss while switching keeps the previous page.
+pub struct SettingsStorage {
+    flash: Option<FLASH>,
+    /// Page holding the latest records and its generation.
+    current: Option<(usize, u32)>,
+    /// Next free slot of the current page.
+    next: usize,
+}
+
+impl SettingsStorage {
+    pub fn new(flash: FLASH) -> Self {
+        let mut storage = Self {
+            flash: Some(flash),
+            current: None,
+            next: 0,
+        };
+        let generations = [storage.generation(0), storage.generation(1)];
+        let page = match generations {
+            [Some(first), Some(second)] => (second.wrapping_sub(first) as i32 > 0) as usize,
+            [None, Some(_)] => 1,
+            [Some(_), None] => 0,
+            [None, None] => return storage,
+        };
+        storage.current = generations[page].map(|generation| (page, generation));
+        storage.next = (0..SLOTS)
+            .find(|&slot| storage.is_free(page, slot))
+            .unwrap_or(SLOTS);
+        storage
+    }
+
+    pub fn load(&mut self) -> Option<Settings> {
+        self.latest(0, Settings::decode)
+    }
+
+    pub fn load_stats(&mut self) -> Option<Stats> {
+        self.latest(Settings::RECORD_SIZE, Stats::decode)
+    }
+
+    /// Appends both records, moving to the other page once this one is full.
+    pub fn save(&mut self, settings: &Settings, stats: &Stats) -> bool {
+        let mut record = [0; SLOT_SIZE];
+        record[..Settings::RECORD_SIZE].copy_from_slice(&settings.encode());
+        record[Settings::RECORD_SIZE..].copy_from_slice(&stats.encode());
+
+        if let Some((page, _)) = self.current.filter(|_| self.next < SLOTS) {
+            let address = slot_address(page, self.next);
+            // A torn write leaves the slot programmed, so it is skipped either way
+            self.next += 1;
+            return self
+                .with_flash(|flash| flash.write(address, &record).is_ok())
+                .unwrap_or(false);
+        }
+
+        let (page, generation) = match self.current {
+            Some((page, generation)) => (1 - page, generation.wrapping_add(1)),
+            None => (0, 0),
+        };
+        let mut header = [0; HEADER_SIZE];
+        header[..4].copy_from_slice(&generation.to_le_bytes());
+        header[4..].copy_from_slice(&(!generation).to_le_bytes());
+        let saved = self
+            .with_flash(|flash| {
+                flash.erase_page(PAGES[page]).is_ok()
+                    && flash.write(slot_address(page, 0), &record).is_ok()
+                    && flash.write(PAGES[page].to_address(), &header).is_ok()
+            })
+            .unwrap_or(false);
+        if saved {
+            self.current = Some((page, generation));
+            self.next = 1;
+        }
+        saved
+    }
+
+    /// Decodes the newest slot holding a valid record at `offset`.
+    fn latest<T, const N: usize>(
+        &mut self,
+        offset: usize,
+        decode: impl Fn(&[u8; N]) -> Option<T>,
+    ) -> Option<T> {
+        let mut record = [0; N];
+        let (page, _) = self.current?;
+        (0..self.next).rev().find_map(|slot| {
+            let address = slot_address(page, slot) + offset;
+            self.with_flash(|flash| flash.read(address, &mut record))?;
+            decode(&record)
+        })
+    }
+
+    fn generation(&mut self, page: usize) -> Option<u32> {
+        let mut header = [0; HEADER_SIZE];
+        self.with_flash(|flash| flash.read(PAGES[page].to_address(), &mut header))?;
+        let generation = u32::from_le_bytes([header[0], header[1], header[2], header[3]]);
+        let check = u32::from_le_bytes([header[4], header[5], header[6], header[7]]);
+        (generation == !check).then_some(generation)
+    }
+
+    fn is_free(&mut self, page: usize, slot: usize) -> bool {
+        let mut word = [0; 8];
+        let read = self.with_flash(|flash| flash.read(slot_address(page, slot), &mut word));
+        read.is_some() && word == [0xff; 8]
+    }
+
+    fn with_flash<T>(&mut self, f: impl FnOnce(&mut UnlockedFlash) -> T) -> Option<T> {
+        match self.flash.take()?.unlock() {
+            Ok(mut unlocked) => {
+                let res = f(&mut unlocked);
+                self.flash = Some(unlocked.lock());
+                Some(res)
+            }
+            Err(flash) => {
+                self.flash = Some(flash);
+                None
+            }
+        }
+    }
+}
+
+fn slot_address(page: usize, slot: usize) -> usize {
+    PAGES[page].to_address() + HEADER_SIZE + slot * SLOT_SIZE
+}